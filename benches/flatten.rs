@@ -0,0 +1,66 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use mandala::{
+    hatch, render_paths_with_stats, to_gcode, Angle, CubicCurve, Float, GlVec, Path,
+    PlotterOptions, Point, SweepArc, VectorValuedFn,
+};
+
+fn point(x: Float, y: Float) -> Point {
+    let mut p: Point = GlVec::ZERO.into();
+    p.x = x;
+    p.y = y;
+    p
+}
+
+fn scene() -> Vec<Path> {
+    (0..64)
+        .map(|i| {
+            let r = 1.0 + i as Float;
+            let mut radius = GlVec::splat(r);
+            radius.y *= 0.5;
+            Path::new(vec![
+                Box::new(SweepArc::ellipse(point(0.0, 0.0), radius.into())),
+                Box::new(CubicCurve {
+                    start: point(r, 0.0),
+                    control1: point(r, r),
+                    control2: point(-r, r),
+                    end: point(-r, 0.0),
+                }),
+            ])
+        })
+        .collect()
+}
+
+fn flattening(c: &mut Criterion) {
+    let paths = scene();
+
+    c.bench_function("sample_optimal", |b| {
+        b.iter(|| {
+            for path in &paths {
+                black_box(path.sample_optimal());
+            }
+        })
+    });
+
+    for tolerance in [1.0, 0.1, 0.01] {
+        c.bench_function(&format!("flatten tolerance {tolerance}"), |b| {
+            b.iter(|| black_box(render_paths_with_stats(&paths, Some(tolerance))))
+        });
+    }
+}
+
+fn exporters(c: &mut Criterion) {
+    let paths = scene();
+    let options = PlotterOptions::default();
+
+    c.bench_function("to_gcode", |b| {
+        b.iter(|| black_box(to_gcode(&paths, &options)))
+    });
+
+    let outline = Path::rectangle(point(0.0, 0.0), GlVec::splat(100.0).into());
+    c.bench_function("hatch", |b| {
+        b.iter(|| black_box(hatch(&outline, 0.5, Angle::FRAC_PI_4)))
+    });
+}
+
+criterion_group!(benches, flattening, exporters);
+criterion_main!(benches);
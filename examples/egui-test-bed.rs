@@ -470,11 +470,11 @@ impl MandalaApp {
 
     fn plot_path(&self, chart: &mut ChartType) {
         let path = mandala::Path::new(vec![
-            Box::new(self.arc.clone()),
-            Box::new(self.arc_segment.clone()),
-            Box::new(self.line_segment.clone()),
-            Box::new(self.cubic.clone()),
-            Box::new(self.quad.clone()),
+            Box::new(self.arc),
+            Box::new(self.arc_segment),
+            Box::new(self.line_segment),
+            Box::new(self.cubic),
+            Box::new(self.quad),
         ]);
         chart
             .draw_series(LineSeries::new(make_series(path), &BLUE))
@@ -143,6 +143,130 @@ impl DivAssign<Float> for Angle {
     }
 }
 
+/// counter-clockwise range of angles from `start` covering `sweep`,
+/// a sweep of [`Angle::TAU`] is the full circle
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct AngularSpan {
+    pub start: Angle,
+    pub sweep: Angle,
+}
+
+impl AngularSpan {
+    pub fn new(start: Angle, sweep: Angle) -> Self {
+        Self { start, sweep }.normalized()
+    }
+
+    /// full circle starting at `start`
+    pub fn full(start: Angle) -> Self {
+        Self {
+            start,
+            sweep: Angle::TAU,
+        }
+    }
+
+    /// span going counter-clockwise from `start` to `end`
+    pub fn between(start: Angle, end: Angle) -> Self {
+        Self::new(start, Angle::from_radians(end.0 - start.0))
+    }
+
+    /// wraps `start` into one turn and clamps `sweep` to at most one turn
+    pub fn normalized(self) -> Self {
+        Self {
+            start: self.start.wrapped(),
+            sweep: sweep_from(self.sweep.0.clamp(0.0, Angle::TAU.0)),
+        }
+    }
+
+    pub fn end(&self) -> Angle {
+        self.start + self.sweep
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.sweep.0 >= Angle::TAU.0 - Float::EPSILON
+    }
+
+    /// angle at fraction `t` of the sweep
+    pub fn at(&self, t: Float) -> Angle {
+        Angle(self.start.0 + self.sweep.0 * t).wrapped()
+    }
+
+    /// radians from `start` to `angle` going counter-clockwise
+    pub fn offset_of(&self, angle: Angle) -> Float {
+        (angle.0 - self.start.0).rem_euclid(Angle::TAU.0)
+    }
+
+    pub fn contains(&self, angle: Angle) -> bool {
+        self.is_full() || self.offset_of(angle) <= self.sweep.0
+    }
+
+    /// overlapping parts, two spans may overlap at both ends
+    pub fn intersection(&self, other: &Self) -> Vec<Self> {
+        if self.is_full() {
+            return vec![*other];
+        }
+        if other.is_full() {
+            return vec![*self];
+        }
+
+        let offset = self.offset_of(other.start);
+        [offset, offset - Angle::TAU.0]
+            .into_iter()
+            .filter_map(|other_start| {
+                let lo = other_start.max(0.0);
+                let hi = (other_start + other.sweep.0).min(self.sweep.0);
+                (hi > lo).then(|| Self {
+                    start: Angle(self.start.0 + lo).wrapped(),
+                    sweep: sweep_from(hi - lo),
+                })
+            })
+            .collect()
+    }
+
+    /// single span covering both, `None` when they neither overlap nor touch
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_full() || other.is_full() {
+            return Some(Self::full(self.start));
+        }
+
+        // spans that touch up to rounding still join
+        let touching = Float::EPSILON.sqrt();
+        let (first, second) = if self.offset_of(other.start) <= self.sweep.0 + touching {
+            (self, other)
+        } else if other.offset_of(self.start) <= other.sweep.0 + touching {
+            (other, self)
+        } else {
+            return None;
+        };
+
+        let end = first
+            .sweep
+            .0
+            .max(first.offset_of(second.start) + second.sweep.0);
+        Some(Self {
+            start: first.start,
+            sweep: sweep_from(end.min(Angle::TAU.0)),
+        })
+    }
+
+    /// `n` equal consecutive parts
+    pub fn split(&self, n: usize) -> impl Iterator<Item = Self> + '_ {
+        let sweep = self.sweep.0 / n as Float;
+        (0..n).map(move |i| Self {
+            start: Angle(self.start.0 + sweep * i as Float).wrapped(),
+            sweep: sweep_from(sweep),
+        })
+    }
+}
+
+/// sweep keeping a full turn instead of wrapping it to zero
+fn sweep_from(radians: Float) -> Angle {
+    if radians >= Angle::TAU.0 - Float::EPSILON {
+        Angle::TAU
+    } else {
+        Angle(radians)
+    }
+}
+
 #[cfg(test)]
 mod angle_tests {
     use cfg_if::cfg_if;
@@ -236,4 +360,57 @@ mod angle_tests {
         let angle = Angle::from_degrees(-90.0);
         assert_eq!(angle.to_degrees(), 270.0);
     }
+
+    #[test]
+    fn test_angular_span_contains() {
+        let span = AngularSpan::new(Angle::from_degrees(300.0), Angle::from_degrees(90.0));
+        assert!(span.contains(Angle::from_degrees(350.0)));
+        assert!(span.contains(Angle::from_degrees(20.0)));
+        assert!(!span.contains(Angle::from_degrees(40.0)));
+        assert!((span.end().to_degrees() - 30.0).abs() < 1e-3);
+        assert!(AngularSpan::full(Angle::ZERO).contains(Angle::PI));
+
+        let between = AngularSpan::between(Angle::from_degrees(350.0), Angle::from_degrees(10.0));
+        assert!((between.sweep.to_degrees() - 20.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_angular_span_intersection() {
+        let a = AngularSpan::new(Angle::from_degrees(300.0), Angle::from_degrees(90.0));
+        let b = AngularSpan::new(Angle::from_degrees(0.0), Angle::from_degrees(90.0));
+        let overlap = a.intersection(&b);
+        assert_eq!(overlap.len(), 1);
+        assert!(overlap[0].start.to_degrees().abs() < 1e-3);
+        assert!((overlap[0].sweep.to_degrees() - 30.0).abs() < 1e-3);
+
+        let c = AngularSpan::new(Angle::from_degrees(90.0), Angle::from_degrees(90.0));
+        assert!(a.intersection(&c).is_empty());
+
+        // two wide spans overlapping at both ends
+        let wide = AngularSpan::new(Angle::ZERO, Angle::from_degrees(270.0));
+        let other = AngularSpan::new(Angle::from_degrees(180.0), Angle::from_degrees(270.0));
+        assert_eq!(wide.intersection(&other).len(), 2);
+    }
+
+    #[test]
+    fn test_angular_span_union_and_split() {
+        let a = AngularSpan::new(Angle::from_degrees(300.0), Angle::from_degrees(90.0));
+        let b = AngularSpan::new(Angle::from_degrees(30.0), Angle::from_degrees(60.0));
+        let union = a.union(&b).unwrap();
+        assert!((union.start.to_degrees() - 300.0).abs() < 1e-3);
+        assert!((union.sweep.to_degrees() - 150.0).abs() < 1e-3);
+        assert_eq!(b.union(&a), Some(union));
+
+        let far = AngularSpan::new(Angle::from_degrees(180.0), Angle::from_degrees(10.0));
+        assert_eq!(a.union(&far), None);
+
+        let wide = AngularSpan::new(Angle::ZERO, Angle::from_degrees(270.0));
+        let other = AngularSpan::new(Angle::from_degrees(180.0), Angle::from_degrees(270.0));
+        assert!(wide.union(&other).unwrap().is_full());
+
+        let parts: Vec<_> = AngularSpan::full(Angle::ZERO).split(4).collect();
+        assert_eq!(parts.len(), 4);
+        assert!((parts[3].start.to_degrees() - 270.0).abs() < 1e-3);
+        assert!((parts[3].sweep.to_degrees() - 90.0).abs() < 1e-3);
+    }
 }
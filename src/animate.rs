@@ -0,0 +1,168 @@
+use crate::{Angle, Float};
+
+/// value changing over time, stepped by elapsed seconds
+/// so motion doesn't depend on the frame rate
+pub trait Animator {
+    type Value;
+
+    /// moves the animation `dt` seconds forward and returns the new value
+    fn advance(&mut self, dt: Float) -> Self::Value;
+}
+
+/// steady rotation, add the angle to an `angle_base` or rotate with it
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Spin {
+    /// radians per second, negative spins clockwise
+    pub speed: Float,
+    pub angle: Angle,
+}
+
+impl Spin {
+    pub fn new(speed: Float) -> Self {
+        Self {
+            speed,
+            angle: Angle::ZERO,
+        }
+    }
+}
+
+impl Animator for Spin {
+    type Value = Angle;
+
+    fn advance(&mut self, dt: Float) -> Angle {
+        self.angle = Angle::from_radians(self.angle.to_radians() + self.speed * dt);
+        self.angle
+    }
+}
+
+/// sine swing between `-amplitude` and `amplitude` every `period` seconds,
+/// add it to a radius, breadth or scale
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Pulse {
+    pub amplitude: Float,
+    pub period: Float,
+    /// seconds since the start
+    pub time: Float,
+}
+
+impl Pulse {
+    pub fn new(amplitude: Float, period: Float) -> Self {
+        Self {
+            amplitude,
+            period,
+            time: 0.0,
+        }
+    }
+
+    fn value(&self) -> Float {
+        oscillate(self.amplitude, self.period, self.time)
+    }
+}
+
+impl Animator for Pulse {
+    type Value = Float;
+
+    fn advance(&mut self, dt: Float) -> Float {
+        self.time = wrap_time(self.time + dt, self.period);
+        self.value()
+    }
+}
+
+/// [`Pulse`] travelling across `count` items, e.g. the segments of an epoch,
+/// each item lags the previous one by `1 / count` of the period
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Wave {
+    pub amplitude: Float,
+    pub period: Float,
+    pub count: usize,
+    /// seconds since the start
+    pub time: Float,
+}
+
+impl Wave {
+    pub fn new(amplitude: Float, period: Float, count: usize) -> Self {
+        Self {
+            amplitude,
+            period,
+            count,
+            time: 0.0,
+        }
+    }
+
+    /// current value for the item at `index`
+    pub fn at(&self, index: usize) -> Float {
+        let lag = index as Float / self.count.max(1) as Float * self.period;
+        oscillate(self.amplitude, self.period, self.time - lag)
+    }
+}
+
+impl Animator for Wave {
+    type Value = Vec<Float>;
+
+    fn advance(&mut self, dt: Float) -> Vec<Float> {
+        self.time = wrap_time(self.time + dt, self.period);
+        (0..self.count).map(|i| self.at(i)).collect()
+    }
+}
+
+fn oscillate(amplitude: Float, period: Float, time: Float) -> Float {
+    if period <= 0.0 {
+        return 0.0;
+    }
+    amplitude * (Angle::TAU.to_radians() * time / period).sin()
+}
+
+/// keeps the clock within one period so long runs don't lose precision
+fn wrap_time(time: Float, period: Float) -> Float {
+    if period > 0.0 {
+        time.rem_euclid(period)
+    } else {
+        time
+    }
+}
+
+#[cfg(test)]
+mod animate_tests {
+    use super::*;
+
+    #[test]
+    fn test_spin_is_frame_rate_independent() {
+        let mut coarse = Spin::new(Angle::PI.to_radians());
+        let mut fine = Spin::new(Angle::PI.to_radians());
+
+        coarse.advance(0.5);
+        for _ in 0..50 {
+            fine.advance(0.01);
+        }
+        assert!((coarse.angle.to_radians() - fine.angle.to_radians()).abs() < 1e-4);
+        assert!((coarse.angle.to_degrees() - 90.0).abs() < 1e-3);
+
+        let mut back = Spin::new(-Angle::FRAC_PI_2.to_radians());
+        assert!((back.advance(1.0).to_degrees() - 270.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_pulse() {
+        let mut pulse = Pulse::new(2.0, 4.0);
+
+        assert!((pulse.advance(1.0) - 2.0).abs() < 1e-4);
+        assert!(pulse.advance(1.0).abs() < 1e-4);
+        assert!((pulse.advance(1.0) + 2.0).abs() < 1e-4);
+        assert!((pulse.advance(42.0) - 2.0).abs() < 1e-3);
+        assert_eq!(Pulse::new(1.0, 0.0).advance(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_wave() {
+        let mut wave = Wave::new(1.0, 4.0, 4);
+
+        let values = wave.advance(1.0);
+        assert_eq!(values.len(), 4);
+        let expected = [1.0, 0.0, -1.0, 0.0];
+        for (value, expected) in values.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-4, "{values:?}");
+        }
+        // a second later every item takes over its predecessor's value
+        assert!((wave.advance(1.0)[1] - values[0]).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{
+    affine_scale, affine_translation, Affine, Angle, Float, GlVec, Path, Point, Vector,
+    VectorValuedFn,
+};
+
+/// axis aligned bounding box
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BBox {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl BBox {
+    pub fn new(min: Point, max: Point) -> Self {
+        Self { min, max }
+    }
+
+    /// smallest box containing all points,
+    /// `None` when there are no points
+    pub fn from_points(points: impl IntoIterator<Item = Vector>) -> Option<Self> {
+        let mut points = points.into_iter().map(GlVec::from);
+        let first = points.next()?;
+        let (min, max) = points.fold((first, first), |(min, max), p| (min.min(p), max.max(p)));
+
+        Some(Self {
+            min: min.into(),
+            max: max.into(),
+        })
+    }
+
+    pub fn width(&self) -> Float {
+        self.max.x - self.min.x
+    }
+
+    pub fn height(&self) -> Float {
+        self.max.y - self.min.y
+    }
+
+    pub fn center(&self) -> Point {
+        ((GlVec::from(self.min) + GlVec::from(self.max)) / 2.0).into()
+    }
+
+    /// whether the point is inside or on the edge of the box
+    pub fn contains(&self, point: Point) -> bool {
+        let p = GlVec::from(point);
+        p.cmpge(self.min.into()).all() && p.cmple(self.max.into()).all()
+    }
+
+    /// whether the boxes overlap or touch
+    pub fn intersects(&self, other: &BBox) -> bool {
+        GlVec::from(self.min).cmple(other.max.into()).all()
+            && GlVec::from(other.min).cmple(self.max.into()).all()
+    }
+
+    /// whether `other` lies entirely inside or on the edge of the box
+    pub fn contains_bbox(&self, other: &BBox) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    /// smallest box containing both boxes
+    pub fn union(&self, other: &BBox) -> Self {
+        Self {
+            min: GlVec::from(self.min).min(other.min.into()).into(),
+            max: GlVec::from(self.max).max(other.max.into()).into(),
+        }
+    }
+
+    /// overlap of the boxes, `None` when they don't touch
+    pub fn intersection(&self, other: &BBox) -> Option<Self> {
+        self.intersects(other).then(|| Self {
+            min: GlVec::from(self.min).max(other.min.into()).into(),
+            max: GlVec::from(self.max).min(other.max.into()).into(),
+        })
+    }
+
+    /// transform placing this box centered in `target`,
+    /// stretched to fill it or uniformly scaled to fit when `preserve_aspect` is set
+    ///
+    /// only `x` and `y` are stretched, `z` keeps the uniform scale
+    pub fn fit_transform(&self, target: &BBox, preserve_aspect: bool) -> Affine {
+        let ratio = |to: Float, from: Float| (from > 0.0).then(|| to / from);
+        let (sx, sy) = (
+            ratio(target.width(), self.width()),
+            ratio(target.height(), self.height()),
+        );
+        let uniform = match (sx, sy) {
+            (Some(sx), Some(sy)) => sx.min(sy),
+            (Some(s), None) | (None, Some(s)) => s,
+            (None, None) => 1.0,
+        };
+        let (sx, sy) = if preserve_aspect {
+            (uniform, uniform)
+        } else {
+            (sx.unwrap_or(uniform), sy.unwrap_or(uniform))
+        };
+
+        let scale = Vector {
+            x: sx,
+            y: sy,
+            #[cfg(feature = "3d")]
+            z: uniform,
+        };
+        affine_translation(target.center().into())
+            * affine_scale(scale)
+            * affine_translation((-GlVec::from(self.center())).into())
+    }
+
+    /// grows the box by `margin` in every direction
+    pub fn expanded(&self, margin: Float) -> Self {
+        Self {
+            min: (GlVec::from(self.min) - margin).into(),
+            max: (GlVec::from(self.max) + margin).into(),
+        }
+    }
+}
+
+/// box turned in the `xy` plane, see [`Path::oriented_bbox`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrientedBBox {
+    pub center: Point,
+    /// extent along the direction of `angle`
+    pub width: Float,
+    /// extent across the direction of `angle`
+    pub height: Float,
+    /// direction of the width side from the `x` axis
+    pub angle: Angle,
+}
+
+impl OrientedBBox {
+    pub fn area(&self) -> Float {
+        self.width * self.height
+    }
+
+    /// corners counter clockwise starting from the one
+    /// lowest along both the width and height directions
+    pub fn corners(&self) -> [Point; 4] {
+        let (sin, cos) = (self.angle.sin(), self.angle.cos());
+        let (w, h) = (self.width / 2.0, self.height / 2.0);
+        [(-w, -h), (w, -h), (w, h), (-w, h)].map(|(u, v)| {
+            let mut corner = self.center;
+            corner.x += u * cos - v * sin;
+            corner.y += u * sin + v * cos;
+            corner
+        })
+    }
+}
+
+/// paths whose bounds intersect the viewport,
+/// everything else is skipped without being rendered
+pub fn paths_in<'p>(paths: &'p [Path], viewport: &'p BBox) -> impl Iterator<Item = &'p Path> {
+    paths
+        .iter()
+        .filter(|p| p.bounds().is_some_and(|b| b.intersects(viewport)))
+}
+
+/// paths scaled and moved so their combined bounds fit `target`,
+/// see [`BBox::fit_transform`]
+pub fn fit_paths(paths: Vec<Path>, target: &BBox, preserve_aspect: bool) -> Vec<Path> {
+    let Some(bounds) = BBox::from_points(paths.iter().flat_map(|p| p.samples_optimal())) else {
+        return paths;
+    };
+    let affine = bounds.fit_transform(target, preserve_aspect);
+    paths.into_iter().map(|p| p.transformed(affine)).collect()
+}
+
+#[cfg(test)]
+mod bbox_tests {
+    use super::*;
+    use crate::test_util::vector;
+
+    #[test]
+    fn test_from_points() {
+        let bbox =
+            BBox::from_points([vector(1.0, 5.0), vector(-2.0, 3.0), vector(4.0, -1.0)]).unwrap();
+
+        assert_eq!(bbox.min, Point::from(vector(-2.0, -1.0)));
+        assert_eq!(bbox.max, Point::from(vector(4.0, 5.0)));
+        assert_eq!(bbox.width(), 6.0);
+        assert_eq!(bbox.height(), 6.0);
+        assert_eq!(bbox.center(), Point::from(vector(1.0, 2.0)));
+        assert_eq!(BBox::from_points([]), None);
+    }
+
+    #[test]
+    fn test_contains_and_intersects() {
+        let a = BBox::new(vector(0.0, 0.0).into(), vector(2.0, 2.0).into());
+        let b = BBox::new(vector(2.0, 1.0).into(), vector(3.0, 3.0).into());
+        let c = BBox::new(vector(2.5, 2.5).into(), vector(3.0, 3.0).into());
+
+        assert!(a.contains(vector(1.0, 2.0).into()));
+        assert!(!a.contains(vector(1.0, 2.5).into()));
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+        assert!(a.expanded(0.5).intersects(&c));
+    }
+
+    #[test]
+    fn test_union_and_intersection() {
+        let a = BBox::new(vector(0.0, 0.0).into(), vector(2.0, 2.0).into());
+        let b = BBox::new(vector(1.0, -1.0).into(), vector(3.0, 1.0).into());
+        let c = BBox::new(vector(5.0, 5.0).into(), vector(6.0, 6.0).into());
+
+        assert_eq!(
+            a.union(&b),
+            BBox::new(vector(0.0, -1.0).into(), vector(3.0, 2.0).into())
+        );
+        assert_eq!(
+            a.intersection(&b),
+            Some(BBox::new(vector(1.0, 0.0).into(), vector(2.0, 1.0).into()))
+        );
+        assert_eq!(a.intersection(&c), None);
+        assert!(a.union(&c).contains_bbox(&a) && a.union(&c).contains_bbox(&c));
+        assert!(!a.contains_bbox(&b));
+    }
+
+    #[test]
+    fn test_oriented_bbox_corners() {
+        let obb = OrientedBBox {
+            center: vector(1.0, 1.0).into(),
+            width: 4.0,
+            height: 2.0,
+            angle: Angle::ZERO,
+        };
+
+        assert_eq!(obb.area(), 8.0);
+        assert_eq!(
+            obb.corners(),
+            [
+                vector(-1.0, 0.0),
+                vector(3.0, 0.0),
+                vector(3.0, 2.0),
+                vector(-1.0, 2.0)
+            ]
+            .map(Point::from)
+        );
+    }
+
+    #[test]
+    fn test_paths_in() {
+        let paths = vec![
+            Path::rectangle(vector(0.0, 0.0).into(), vector(1.0, 1.0)),
+            Path::rectangle(vector(5.0, 5.0).into(), vector(1.0, 1.0)),
+            Path::default(),
+        ];
+        let viewport = BBox::new(vector(4.0, 4.0).into(), vector(10.0, 10.0).into());
+
+        let visible: Vec<_> = paths_in(&paths, &viewport)
+            .map(|p| p.bounds().unwrap())
+            .collect();
+        assert_eq!(
+            visible,
+            vec![BBox::new(vector(5.0, 5.0).into(), vector(6.0, 6.0).into())]
+        );
+    }
+
+    #[test]
+    fn test_fit_paths() {
+        let paths = vec![
+            Path::rectangle(vector(10.0, 10.0).into(), vector(2.0, 1.0)),
+            Path::rectangle(vector(12.0, 11.0).into(), vector(2.0, 1.0)),
+        ];
+        let target = BBox::new(vector(0.0, 0.0).into(), vector(100.0, 100.0).into());
+
+        let fitted = fit_paths(paths, &target, true);
+        let bounds = BBox::from_points(fitted.iter().flat_map(|p| p.sample_optimal())).unwrap();
+        assert_eq!(bounds.min, Point::from(vector(0.0, 25.0)));
+        assert_eq!(bounds.max, Point::from(vector(100.0, 75.0)));
+
+        let stretched = fit_paths(fitted, &target, false);
+        let bounds = BBox::from_points(stretched.iter().flat_map(|p| p.sample_optimal())).unwrap();
+        assert_eq!(bounds, target);
+    }
+}
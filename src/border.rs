@@ -0,0 +1,145 @@
+use crate::{
+    affine_rotation, affine_translation, Angle, Float, GlVec, MotifRenderer, Path, VectorValuedFn,
+};
+
+/// stamps motifs every `spacing` along the path
+///
+/// `renderer` draws the motif for the stamp index around the origin
+/// with `x` pointing along the path, each stamp is then rotated to the tangent
+/// and moved onto the path, closed paths get evenly fitted spacing
+///
+/// stamps are spaced by arc length, not by the path parameter,
+/// so they stay even where the parametrization speeds up or slows down
+pub fn generate_border<F: VectorValuedFn + ?Sized>(
+    path: &F,
+    spacing: Float,
+    mut renderer: impl MotifRenderer,
+) -> Vec<Path> {
+    let length = path.length();
+    if spacing <= 0.0 || length <= 0.0 {
+        return Vec::new();
+    }
+
+    let closed = GlVec::from(path.start()).distance(path.end().into()) <= Float::EPSILON.sqrt();
+    let (count, step) = if closed {
+        let count = (length / spacing).round().max(1.0) as usize;
+        (count, length / count as Float)
+    } else {
+        ((length / spacing).floor() as usize + 1, spacing)
+    };
+    let distances: Vec<Float> = (0..count).map(|i| i as Float * step).collect();
+
+    path.params_at_lengths(&distances)
+        .into_iter()
+        .enumerate()
+        .flat_map(|(i, t)| {
+            let tangent = GlVec::from(path.derivative(t));
+            let angle = Angle::from_radians(tangent.y.atan2(tangent.x));
+            let placement = affine_translation(path.eval(t)) * affine_rotation(angle);
+            renderer
+                .render(i)
+                .into_iter()
+                .map(move |motif| motif.transformed(placement))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod border_tests {
+    use super::*;
+    use crate::test_util::vector;
+    use crate::{CubicCurve, LineSegment, MotifLayer, MotifSpec, Point, Polyline, SweepArc};
+
+    fn tick() -> Vec<Path> {
+        vec![Path::new(vec![Box::new(LineSegment {
+            start: vector(0.0, 0.0).into(),
+            end: vector(1.0, 0.0).into(),
+        })])]
+    }
+
+    #[test]
+    fn test_border_along_line() {
+        let line = LineSegment {
+            start: Point::from(vector(0.0, 0.0)),
+            end: Point::from(vector(0.0, 10.0)),
+        };
+        let mut indices = Vec::new();
+        let border = generate_border(&line, 2.5, |i| {
+            indices.push(i);
+            tick()
+        });
+
+        assert_eq!(indices, vec![0, 1, 2, 3, 4]);
+        for (i, stamp) in border.iter().enumerate() {
+            let (start, end) = (GlVec::from(stamp.start()), GlVec::from(stamp.end()));
+            assert!((start - GlVec::from(vector(0.0, i as Float * 2.5))).length() < 1e-3);
+            // the tick follows the upward tangent
+            assert!((end - start - GlVec::from(vector(0.0, 1.0))).length() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_border_with_stateful_renderer() {
+        struct Alternating {
+            shapes: Vec<Vec<Path>>,
+        }
+
+        impl MotifRenderer for Alternating {
+            fn render(&mut self, index: usize) -> Vec<Path> {
+                let shape = &self.shapes[index % self.shapes.len()];
+                shape.iter().map(|p| Polyline::from(p).into()).collect()
+            }
+        }
+
+        let line = LineSegment {
+            start: Point::from(vector(0.0, 0.0)),
+            end: Point::from(vector(10.0, 0.0)),
+        };
+        let renderer: Box<dyn MotifRenderer> = Box::new(Alternating {
+            shapes: vec![tick(), vec![]],
+        });
+        assert_eq!(generate_border(&line, 1.0, renderer).len(), 6);
+
+        let spec = MotifSpec {
+            layers: vec![MotifLayer::Dots {
+                count: 2,
+                radius: 0.5,
+                size: 0.1,
+            }],
+        };
+        assert_eq!(generate_border(&line, 5.0, spec).len(), 6);
+    }
+
+    #[test]
+    fn test_border_around_circle() {
+        let circle = SweepArc::ellipse(vector(0.0, 0.0).into(), vector(10.0, 10.0));
+        let border = generate_border(&circle, 1.0, |_| tick());
+        assert_eq!(border.len(), (circle.length() / 1.0).round() as usize);
+
+        for stamp in &border {
+            let start = GlVec::from(stamp.start());
+            let end = GlVec::from(stamp.end());
+            // ticks are tangent, perpendicular to the radius
+            assert!((start.length() - 10.0).abs() < 1e-2);
+            assert!((end - start).dot(start).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_border_on_uneven_cubic() {
+        // controls bunched at the start make the parameter race near the end
+        let curve = CubicCurve {
+            start: vector(0.0, 0.0).into(),
+            control1: vector(0.5, 0.5).into(),
+            control2: vector(1.0, 0.5).into(),
+            end: vector(10.0, 6.0).into(),
+        };
+        let border = generate_border(&curve, 0.5, |_| tick());
+        assert_eq!(border.len(), (curve.length() / 0.5).floor() as usize + 1);
+
+        for pair in border.windows(2) {
+            let gap = GlVec::from(pair[0].start()).distance(pair[1].start().into());
+            assert!((gap - 0.5).abs() < 1e-2, "gap {gap}");
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{
+    affine_rotation, affine_scale, affine_translation, flow_field::lattice, Affine, Angle, Float,
+    GlVec, Path, Polyline, VectorValuedFn,
+};
+
+/// largest random changes of each stamp, zero keeps stamps exact
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Jitter {
+    /// distance across the path
+    pub offset: Float,
+    /// turn away from the tangent
+    pub angle: Angle,
+    /// fraction the stamp grows or shrinks by
+    pub scale: Float,
+}
+
+/// stamps a motif along a path for textured strokes
+///
+/// the motif is drawn around the origin with `x` pointing along the path,
+/// the same seed always gives the same jitter
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Brush {
+    /// distance between stamps along the path
+    pub spacing: Float,
+    pub jitter: Jitter,
+    pub seed: u32,
+}
+
+impl Brush {
+    pub fn new(spacing: Float) -> Self {
+        Self {
+            spacing,
+            jitter: Jitter::default(),
+            seed: 0,
+        }
+    }
+
+    pub fn with_jitter(mut self, jitter: Jitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// transform of every stamp from the motif space onto the path,
+    /// raster backends can use them to place a bitmap
+    pub fn placements<F: VectorValuedFn + ?Sized>(&self, path: &F) -> Vec<Affine> {
+        let length = path.length();
+        if self.spacing <= 0.0 || length <= 0.0 {
+            return Vec::new();
+        }
+
+        let count = (length / self.spacing).floor() as usize + 1;
+        let distances: Vec<Float> = (0..count).map(|i| i as Float * self.spacing).collect();
+        path.params_at_lengths(&distances)
+            .into_iter()
+            .enumerate()
+            .map(|(i, t)| {
+                let random = |channel: i32| lattice(i as i32, channel, self.seed) * 2.0 - 1.0;
+
+                let tangent = GlVec::from(path.derivative(t));
+                let along = tangent.y.atan2(tangent.x);
+                let angle = Angle::from_radians(along + self.jitter.angle.to_radians() * random(1));
+                let mut position = GlVec::from(path.eval(t));
+                let offset = self.jitter.offset * random(0);
+                position.x -= along.sin() * offset;
+                position.y += along.cos() * offset;
+                let scale = (1.0 + self.jitter.scale * random(2)).max(0.0);
+
+                affine_translation(position.into())
+                    * affine_rotation(angle)
+                    * affine_scale((GlVec::ONE * scale).into())
+            })
+            .collect()
+    }
+
+    /// flattened copies of the motif at every placement keeping its metadata
+    pub fn stamp<F: VectorValuedFn + ?Sized>(&self, path: &F, motif: &[Path]) -> Vec<Path> {
+        self.placements(path)
+            .into_iter()
+            .flat_map(|placement| {
+                motif.iter().map(move |m| {
+                    let mut copy: Path = Polyline::from(m).into();
+                    copy.meta = m.meta.clone();
+                    copy.transformed(placement)
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod brush_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{transform_point, CubicCurve, LineSegment};
+
+    fn dash() -> Vec<Path> {
+        vec![Polyline(vec![point(-0.5, 0.0), point(0.5, 0.0)]).into()]
+    }
+
+    #[test]
+    fn test_stamp_along_line() {
+        let line = LineSegment {
+            start: point(0.0, 0.0),
+            end: point(0.0, 10.0),
+        };
+        let stamps = Brush::new(2.0).stamp(&line, &dash());
+
+        assert_eq!(stamps.len(), 6);
+        for (i, stamp) in stamps.iter().enumerate() {
+            let mid = GlVec::from(stamp.start()).lerp(stamp.end().into(), 0.5);
+            assert!((mid - GlVec::from(point(0.0, i as Float * 2.0))).length() < 1e-4);
+            // dashes follow the upward tangent
+            assert!((stamp.end().y - stamp.start().y - 1.0).abs() < 1e-4);
+        }
+        assert!(Brush::new(0.0).stamp(&line, &dash()).is_empty());
+    }
+
+    #[test]
+    fn test_jitter_is_bounded_and_seeded() {
+        let line = LineSegment {
+            start: point(0.0, 0.0),
+            end: point(20.0, 0.0),
+        };
+        let jitter = Jitter {
+            offset: 0.5,
+            angle: Angle::from_degrees(10.0),
+            scale: 0.2,
+        };
+        let brush = Brush::new(1.0).with_jitter(jitter).with_seed(7);
+        let stamps = brush.stamp(&line, &dash());
+
+        assert_eq!(stamps.len(), 21);
+        let lengths: Vec<Float> = stamps.iter().map(|s| s.length()).collect();
+        assert!(lengths
+            .iter()
+            .all(|l| (0.8 - 1e-4..=1.2 + 1e-4).contains(l)));
+        assert!(lengths.iter().any(|l| (l - 1.0).abs() > 1e-3));
+        for (i, stamp) in stamps.iter().enumerate() {
+            let mid = GlVec::from(stamp.start()).lerp(stamp.end().into(), 0.5);
+            assert!(mid.y.abs() <= 0.5 + 1e-4);
+            assert!((mid.x - i as Float).abs() < 1e-4);
+        }
+
+        let again: Vec<Float> = brush
+            .stamp(&line, &dash())
+            .iter()
+            .map(|s| s.length())
+            .collect();
+        assert_eq!(again, lengths);
+        let other: Vec<Float> = brush
+            .with_seed(8)
+            .stamp(&line, &dash())
+            .iter()
+            .map(|s| s.length())
+            .collect();
+        assert_ne!(other, lengths);
+    }
+
+    #[test]
+    fn test_placements_by_arc_length() {
+        let curve = CubicCurve {
+            start: point(0.0, 0.0),
+            control1: point(0.5, 0.5),
+            control2: point(1.0, 0.5),
+            end: point(10.0, 6.0),
+        };
+        let placements = Brush::new(0.5).placements(&curve);
+        assert_eq!(
+            placements.len(),
+            (curve.length() / 0.5).floor() as usize + 1
+        );
+
+        let origins: Vec<GlVec> = placements
+            .iter()
+            .map(|a| GlVec::from(transform_point(a, point(0.0, 0.0).into())))
+            .collect();
+        for pair in origins.windows(2) {
+            let gap = pair[0].distance(pair[1]);
+            assert!((gap - 0.5).abs() < 1e-2, "gap {gap}");
+        }
+    }
+}
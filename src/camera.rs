@@ -0,0 +1,172 @@
+use crate::{Angle, Float, GlVec, Path, Point, Polyline, Vector, VectorValuedFn};
+
+/// depth of the near plane, points at it or behind are not projected
+const NEAR: Float = Float::EPSILON;
+
+/// perspective camera looking from `eye` at `target`
+///
+/// projected points are in view units where the vertical field of view spans `-1..1`
+#[derive(Debug, Clone, Copy)]
+pub struct Camera {
+    pub eye: Point,
+    pub target: Point,
+    /// vertical field of view
+    pub fov: Angle,
+    pub up: Vector,
+}
+
+impl Camera {
+    /// camera with `z` up
+    pub fn new(eye: Point, target: Point, fov: Angle) -> Self {
+        Self {
+            eye,
+            target,
+            fov,
+            up: GlVec::Z.into(),
+        }
+    }
+
+    /// right, up and forward axes of the view
+    fn basis(&self) -> (GlVec, GlVec, GlVec) {
+        let forward = (GlVec::from(self.target) - GlVec::from(self.eye)).normalize_or_zero();
+        let mut right = forward.cross(GlVec::from(self.up)).normalize_or_zero();
+        if right == GlVec::ZERO {
+            right = forward.any_orthonormal_vector();
+        }
+        (right, right.cross(forward), forward)
+    }
+
+    /// projects a point onto the image plane, `None` when it's behind the camera
+    pub fn project_point(&self, point: Vector) -> Option<[Float; 2]> {
+        let view = self.view_of(point);
+        (view.z > NEAR).then(|| self.image_of(view))
+    }
+
+    /// point relative to the eye along right, up and depth
+    fn view_of(&self, point: Vector) -> GlVec {
+        let (right, up, forward) = self.basis();
+        let d = GlVec::from(point) - GlVec::from(self.eye);
+        GlVec::new(d.dot(right), d.dot(up), d.dot(forward))
+    }
+
+    fn image_of(&self, view: GlVec) -> [Float; 2] {
+        let scale = view.z * (self.fov.to_radians() / 2.0).tan();
+        [view.x / scale, view.y / scale]
+    }
+}
+
+/// projects the flattened curve through the camera onto the `xy` plane,
+/// parts behind the camera are clipped at the near plane
+/// splitting the result into several paths
+pub fn project<F: VectorValuedFn + ?Sized>(curve: &F, camera: &Camera) -> Vec<Path> {
+    let mut paths = Vec::new();
+    let mut current: Vec<Point> = Vec::new();
+    let mut previous: Option<GlVec> = None;
+    let image = |view: GlVec| {
+        let [x, y] = camera.image_of(view);
+        Point { x, y, z: 0.0 }
+    };
+
+    for sample in curve.samples_optimal() {
+        let view = camera.view_of(sample);
+        let visible = view.z > NEAR;
+
+        // edges crossing the near plane keep their visible part
+        if let Some(prev) = previous.filter(|prev| (prev.z > NEAR) != visible) {
+            let mut crossing = prev.lerp(view, (prev.z - NEAR) / (prev.z - view.z));
+            // rounding may leave the interpolated depth off the plane
+            crossing.z = NEAR;
+            current.push(image(crossing));
+            if !visible {
+                if current.len() > 1 {
+                    paths.push(Polyline(std::mem::take(&mut current)).into());
+                }
+                current.clear();
+            }
+        }
+        if visible {
+            current.push(image(view));
+        }
+        previous = Some(view);
+    }
+
+    if current.len() > 1 {
+        paths.push(Polyline(current).into());
+    }
+
+    paths
+}
+
+#[cfg(test)]
+mod camera_tests {
+    use super::*;
+    use crate::LineSegment;
+
+    fn point(x: Float, y: Float, z: Float) -> Point {
+        Point { x, y, z }
+    }
+
+    #[test]
+    fn test_project_point() {
+        let camera = Camera::new(
+            point(0.0, -10.0, 0.0),
+            point(0.0, 0.0, 0.0),
+            Angle::FRAC_PI_2,
+        );
+
+        let center = camera.project_point(point(0.0, 0.0, 0.0).into()).unwrap();
+        assert!(center[0].abs() < 1e-5 && center[1].abs() < 1e-5);
+
+        // at 90° the edge of the view is as far from the axis as the point is deep
+        let corner = camera.project_point(point(10.0, 0.0, 5.0).into()).unwrap();
+        assert!((corner[0] - 1.0).abs() < 1e-5);
+        assert!((corner[1] - 0.5).abs() < 1e-5);
+
+        assert!(camera
+            .project_point(point(0.0, -20.0, 0.0).into())
+            .is_none());
+    }
+
+    #[test]
+    fn test_project_splits_behind_camera() {
+        let camera = Camera::new(point(0.0, 0.0, 0.0), point(0.0, 1.0, 0.0), Angle::FRAC_PI_2);
+        let through = LineSegment {
+            start: point(-1.0, 5.0, 0.0),
+            end: point(1.0, 5.0, 0.0),
+        };
+        let paths = project(&through, &camera);
+        assert_eq!(paths.len(), 1);
+        assert!((paths[0].start().x + 0.2).abs() < 1e-5);
+
+        let behind = LineSegment {
+            start: point(0.0, -5.0, 0.0),
+            end: point(0.0, -1.0, 0.0),
+        };
+        assert!(project(&behind, &camera).is_empty());
+    }
+
+    #[test]
+    fn test_project_clips_at_near_plane() {
+        let camera = Camera::new(point(0.0, 0.0, 0.0), point(0.0, 1.0, 0.0), Angle::FRAC_PI_2);
+        let mut line = LineSegment {
+            start: point(1.0, 5.0, 0.0),
+            end: point(1.0, -5.0, 0.0),
+        };
+
+        for _ in 0..2 {
+            let paths = project(&line, &camera);
+            assert_eq!(paths.len(), 1);
+            // the visible half runs from the far end off towards the eye
+            let (start, end) = (paths[0].start().x, paths[0].end().x);
+            let (near, far) = if start < end {
+                (end, start)
+            } else {
+                (start, end)
+            };
+            assert!((far - 0.2).abs() < 1e-5);
+            assert!(near > 1e3);
+
+            (line.start, line.end) = (line.end, line.start);
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use crate::{Float, Path, Primitive, VectorValuedFn};
+
+/// one call on a Canvas 2D rendering context, angles in radians
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CanvasCmd {
+    BeginPath,
+    MoveTo {
+        x: Float,
+        y: Float,
+    },
+    LineTo {
+        x: Float,
+        y: Float,
+    },
+    QuadraticCurveTo {
+        cpx: Float,
+        cpy: Float,
+        x: Float,
+        y: Float,
+    },
+    BezierCurveTo {
+        cp1x: Float,
+        cp1y: Float,
+        cp2x: Float,
+        cp2y: Float,
+        x: Float,
+        y: Float,
+    },
+    Arc {
+        x: Float,
+        y: Float,
+        radius: Float,
+        start_angle: Float,
+        end_angle: Float,
+    },
+    Ellipse {
+        x: Float,
+        y: Float,
+        radius_x: Float,
+        radius_y: Float,
+        start_angle: Float,
+        end_angle: Float,
+    },
+    ClosePath,
+    Stroke,
+}
+
+/// Canvas 2D calls drawing the paths, one stroked canvas path per path
+///
+/// lines and bezier curves are kept, arcs become `arc` or `ellipse`
+/// running clockwise on screen, other segments are flattened,
+/// the canvas is flat so `z` coordinates are dropped
+pub fn to_canvas_commands(paths: &[Path]) -> Vec<CanvasCmd> {
+    let mut commands = Vec::new();
+
+    for path in paths.iter().filter(|p| !p.segments().is_empty()) {
+        commands.push(CanvasCmd::BeginPath);
+        let mut current: Option<[Float; 2]> = None;
+
+        for segment in path.segments() {
+            let start = segment.start();
+            if current != Some([start.x, start.y]) {
+                commands.push(CanvasCmd::MoveTo {
+                    x: start.x,
+                    y: start.y,
+                });
+            }
+
+            match segment.primitive() {
+                Some(Primitive::Line(l)) => commands.push(CanvasCmd::LineTo {
+                    x: l.end.x,
+                    y: l.end.y,
+                }),
+                Some(Primitive::QuadraticCurve(c)) => commands.push(CanvasCmd::QuadraticCurveTo {
+                    cpx: c.control.x,
+                    cpy: c.control.y,
+                    x: c.end.x,
+                    y: c.end.y,
+                }),
+                Some(Primitive::CubicCurve(c)) => commands.push(CanvasCmd::BezierCurveTo {
+                    cp1x: c.control1.x,
+                    cp1y: c.control1.y,
+                    cp2x: c.control2.x,
+                    cp2y: c.control2.y,
+                    x: c.end.x,
+                    y: c.end.y,
+                }),
+                Some(Primitive::Arc(arc)) => {
+                    let start_angle = arc.start_angle.to_radians();
+                    let end_angle = start_angle + arc.sweep_angle.to_radians();
+                    commands.push(if arc.radius.x == arc.radius.y {
+                        CanvasCmd::Arc {
+                            x: arc.center.x,
+                            y: arc.center.y,
+                            radius: arc.radius.x,
+                            start_angle,
+                            end_angle,
+                        }
+                    } else {
+                        CanvasCmd::Ellipse {
+                            x: arc.center.x,
+                            y: arc.center.y,
+                            radius_x: arc.radius.x,
+                            radius_y: arc.radius.y,
+                            start_angle,
+                            end_angle,
+                        }
+                    });
+                }
+                None => commands.extend(
+                    segment
+                        .sample_optimal()
+                        .into_iter()
+                        .skip(1)
+                        .map(|v| CanvasCmd::LineTo { x: v.x, y: v.y }),
+                ),
+            }
+
+            let end = segment.end();
+            current = Some([end.x, end.y]);
+        }
+
+        let (start, end) = (path.start(), path.end());
+        if path.segments().len() > 1 && [start.x, start.y] == [end.x, end.y] {
+            commands.push(CanvasCmd::ClosePath);
+        }
+        commands.push(CanvasCmd::Stroke);
+    }
+
+    commands
+}
+
+#[cfg(test)]
+mod canvas_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{CubicCurve, SweepArc, Vector};
+
+    #[test]
+    fn test_rectangle_commands() {
+        let square = Path::rectangle(point(0.0, 0.0), Vector::from(point(1.0, 2.0)));
+
+        assert_eq!(
+            to_canvas_commands(&[square, Path::default()]),
+            vec![
+                CanvasCmd::BeginPath,
+                CanvasCmd::MoveTo { x: 0.0, y: 0.0 },
+                CanvasCmd::LineTo { x: 1.0, y: 0.0 },
+                CanvasCmd::LineTo { x: 1.0, y: 2.0 },
+                CanvasCmd::LineTo { x: 0.0, y: 2.0 },
+                CanvasCmd::LineTo { x: 0.0, y: 0.0 },
+                CanvasCmd::ClosePath,
+                CanvasCmd::Stroke,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_curves_and_arcs() {
+        let mut path = Path::new(vec![Box::new(CubicCurve {
+            start: point(0.0, 0.0),
+            control1: point(1.0, 1.0),
+            control2: point(2.0, 1.0),
+            end: point(3.0, 0.0),
+        })]);
+        path.push(Box::new(SweepArc::ellipse(
+            point(5.0, 0.0),
+            Vector::from(point(2.0, 2.0)),
+        )));
+        path.push(Box::new(SweepArc::ellipse(
+            point(0.0, 0.0),
+            Vector::from(point(2.0, 1.0)),
+        )));
+        let commands = to_canvas_commands(&[path]);
+
+        assert_eq!(commands.len(), 8);
+        assert!(matches!(
+            commands[2],
+            CanvasCmd::BezierCurveTo { x: 3.0, .. }
+        ));
+        assert_eq!(commands[3], CanvasCmd::MoveTo { x: 7.0, y: 0.0 });
+        assert!(matches!(
+            commands[4],
+            CanvasCmd::Arc {
+                radius: 2.0,
+                start_angle: 0.0,
+                ..
+            }
+        ));
+        assert!(matches!(
+            commands[6],
+            CanvasCmd::Ellipse {
+                radius_x: 2.0,
+                radius_y: 1.0,
+                ..
+            }
+        ));
+        assert_eq!(commands[7], CanvasCmd::Stroke);
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{
+    line_intersection, ring_contains, Float, GlVec, LineSegment, Path, Point, Polyline, Tolerance,
+    VectorValuedFn,
+};
+
+/// parts of the flattened paths inside the closed outline,
+/// one polyline path per continuous inside run
+///
+/// the outline is flattened in the `xy` plane, insideness is even-odd
+/// and points on the outline count as inside, the metadata is kept
+pub fn clip_paths(paths: &[Path], outline: &Path) -> Vec<Path> {
+    let mut ring: Vec<Point> = outline.samples_optimal().map(Point::from).collect();
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    if ring.len() < 3 {
+        return Vec::new();
+    }
+
+    let edges: Vec<LineSegment> = ring
+        .iter()
+        .zip(ring.iter().cycle().skip(1))
+        .map(|(a, b)| LineSegment { start: *a, end: *b })
+        .collect();
+    let tolerance = Tolerance::default();
+    let inside = |p: GlVec| ring_contains(&ring, p.into(), tolerance);
+
+    let mut clipped = Vec::new();
+    for path in paths {
+        let points: Vec<GlVec> = path.samples_optimal().map(GlVec::from).collect();
+        let mut run: Vec<GlVec> = Vec::new();
+        let mut flush = |run: &mut Vec<GlVec>| {
+            if run.len() > 1 {
+                let mut piece: Path =
+                    Polyline(run.iter().map(|p| Point::from(*p)).collect()).into();
+                piece.meta = path.meta.clone();
+                clipped.push(piece);
+            }
+            run.clear();
+        };
+
+        for w in points.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            let mut cuts = crossings(a, b, &edges, tolerance);
+            cuts.push(1.0);
+
+            let mut from = 0.0;
+            for to in cuts {
+                if to <= from {
+                    continue;
+                }
+                if inside(a.lerp(b, (from + to) / 2.0)) {
+                    if run.is_empty() {
+                        run.push(a.lerp(b, from));
+                    }
+                    run.push(a.lerp(b, to));
+                } else {
+                    flush(&mut run);
+                }
+                from = to;
+            }
+        }
+        flush(&mut run);
+    }
+
+    clipped
+}
+
+/// sorted parameters along `a`-`b` where it crosses the edges
+fn crossings(a: GlVec, b: GlVec, edges: &[LineSegment], tolerance: Tolerance) -> Vec<Float> {
+    let line = LineSegment {
+        start: a.into(),
+        end: b.into(),
+    };
+    let d = b - a;
+    let len_sq = d.length_squared();
+    if len_sq == 0.0 {
+        return Vec::new();
+    }
+
+    let mut cuts: Vec<Float> = edges
+        .iter()
+        .filter_map(|edge| line_intersection(&line, edge, tolerance))
+        .map(|p| ((GlVec::from(p) - a).dot(d) / len_sq).clamp(0.0, 1.0))
+        .collect();
+    cuts.sort_by(|x, y| x.total_cmp(y));
+    cuts
+}
+
+#[cfg(test)]
+mod clip_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::Vector;
+
+    #[test]
+    fn test_clip_line_through_square() {
+        let square = Path::rectangle(point(0.0, 0.0), Vector::from(point(4.0, 4.0)));
+        let line: Path = Polyline(vec![point(-2.0, 2.0), point(6.0, 2.0)]).into();
+
+        let clipped = clip_paths(&[line.with_meta("layer", "fill")], &square);
+        assert_eq!(clipped.len(), 1);
+        assert_eq!(
+            clipped[0].sample_optimal(),
+            vec![Vector::from(point(0.0, 2.0)), Vector::from(point(4.0, 2.0))]
+        );
+        assert_eq!(clipped[0].get_meta("layer"), Some("fill"));
+    }
+
+    #[test]
+    fn test_clip_splits_runs() {
+        let square = Path::rectangle(point(0.0, 0.0), Vector::from(point(4.0, 4.0)));
+        let zigzag: Path = Polyline(vec![
+            point(1.0, 1.0),
+            point(1.0, 6.0),
+            point(2.0, 6.0),
+            point(2.0, 1.0),
+            point(3.0, 1.0),
+        ])
+        .into();
+        let outside: Path = Polyline(vec![point(5.0, 5.0), point(8.0, 5.0)]).into();
+
+        let clipped = clip_paths(&[zigzag, outside], &square);
+        assert_eq!(clipped.len(), 2);
+        assert_eq!(
+            clipped[0].sample_optimal(),
+            vec![Vector::from(point(1.0, 1.0)), Vector::from(point(1.0, 4.0))]
+        );
+        assert_eq!(
+            clipped[1].sample_optimal(),
+            vec![
+                Vector::from(point(2.0, 4.0)),
+                Vector::from(point(2.0, 1.0)),
+                Vector::from(point(3.0, 1.0))
+            ]
+        );
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use crate::{DistanceField, Float, Path, Point, Polyline};
+
+/// closed paths where the field crosses `iso`, inside of them is below `iso`
+///
+/// contours are polylines through the crossings on the grid edges
+/// in the plane of the field bounds
+pub fn contour(field: &DistanceField, iso: Float) -> Vec<Path> {
+    let (width, height) = (field.width + 2, field.height + 2);
+    // flipped so inside is above the level and padded so every contour closes
+    let padding = field.values.iter().fold(-iso, |m, v| m.min(-v)) - 1.0;
+    let mut values = vec![padding; width * height];
+    for y in 0..field.height {
+        for x in 0..field.width {
+            values[(y + 1) * width + x + 1] = -field.get(x, y);
+        }
+    }
+
+    let cell = field.cell_size();
+    marching_squares(&values, width, height, -iso)
+        .into_iter()
+        .map(|contour| {
+            let mut points: Vec<Point> = contour
+                .into_iter()
+                .map(|[x, y]| {
+                    let mut p = field.bounds.min;
+                    p.x += (x - 0.5) * cell;
+                    p.y += (y - 0.5) * cell;
+                    p
+                })
+                .collect();
+            if points.first() != points.last() {
+                points.push(points[0]);
+            }
+            Polyline(points).into()
+        })
+        .collect()
+}
+
+/// finds closed contours at `iso` level of a grid of values,
+/// the grid must be padded with values below `iso`
+pub(crate) fn marching_squares(
+    values: &[Float],
+    width: usize,
+    height: usize,
+    iso: Float,
+) -> Vec<Vec<[Float; 2]>> {
+    let value = |x: usize, y: usize| values[y * width + x];
+    let inside = |x: usize, y: usize| value(x, y) >= iso;
+
+    // every crossing lives on a grid edge,
+    // horizontal edges have even ids and vertical edges odd
+    let crossing = |id: usize| -> [Float; 2] {
+        let cell = id / 2;
+        let (x, y) = (cell % width, cell / width);
+        let (x1, y1) = if id.is_multiple_of(2) {
+            (x + 1, y)
+        } else {
+            (x, y + 1)
+        };
+        let (v0, v1) = (value(x, y), value(x1, y1));
+        let f = if v1 == v0 {
+            0.5
+        } else {
+            (iso - v0) / (v1 - v0)
+        };
+        [
+            x as Float + (x1 - x) as Float * f,
+            y as Float + (y1 - y) as Float * f,
+        ]
+    };
+
+    let mut links: HashMap<usize, Vec<usize>> = HashMap::new();
+
+    for y in 0..height - 1 {
+        for x in 0..width - 1 {
+            let top = 2 * (y * width + x);
+            let bottom = 2 * ((y + 1) * width + x);
+            let left = top + 1;
+            let right = 2 * (y * width + x + 1) + 1;
+
+            let case = inside(x, y) as u8
+                | (inside(x + 1, y) as u8) << 1
+                | (inside(x + 1, y + 1) as u8) << 2
+                | (inside(x, y + 1) as u8) << 3;
+
+            let center_inside =
+                (value(x, y) + value(x + 1, y) + value(x + 1, y + 1) + value(x, y + 1)) / 4.0
+                    >= iso;
+
+            let pairs: &[(usize, usize)] = match case {
+                1 | 14 => &[(left, top)],
+                2 | 13 => &[(top, right)],
+                3 | 12 => &[(left, right)],
+                4 | 11 => &[(right, bottom)],
+                6 | 9 => &[(top, bottom)],
+                7 | 8 => &[(left, bottom)],
+                5 if center_inside => &[(left, bottom), (top, right)],
+                5 => &[(left, top), (right, bottom)],
+                10 if center_inside => &[(left, top), (right, bottom)],
+                10 => &[(top, right), (left, bottom)],
+                _ => &[],
+            };
+
+            for &(a, b) in pairs {
+                links.entry(a).or_default().push(b);
+                links.entry(b).or_default().push(a);
+            }
+        }
+    }
+
+    let mut starts: Vec<usize> = links.keys().copied().collect();
+    starts.sort_unstable();
+
+    let mut contours = Vec::new();
+
+    for start in starts {
+        if !links.contains_key(&start) {
+            continue;
+        }
+
+        let mut contour = vec![crossing(start)];
+        let mut current = start;
+
+        while let Some(next) = links.get_mut(&current).and_then(|n| n.pop()) {
+            if let Some(back) = links.get_mut(&next) {
+                if let Some(i) = back.iter().position(|&b| b == current) {
+                    back.swap_remove(i);
+                }
+            }
+            if links.get(&current).is_some_and(|n| n.is_empty()) {
+                links.remove(&current);
+            }
+            contour.push(crossing(next));
+            current = next;
+        }
+        links.remove(&current);
+
+        if contour.len() > 2 {
+            contours.push(contour);
+        }
+    }
+
+    contours
+}
+
+#[cfg(test)]
+mod contour_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{Vector, VectorValuedFn};
+
+    #[test]
+    fn test_contour_round_trip() {
+        let square = Path::rectangle(point(0.0, 0.0), Vector::from(point(16.0, 16.0)));
+        let field = square.to_sdf(40).unwrap();
+
+        let outlines = contour(&field, 0.0);
+        assert_eq!(outlines.len(), 1);
+        assert!(
+            (outlines[0].area() - 256.0).abs() < 4.0,
+            "{}",
+            outlines[0].area()
+        );
+        assert_eq!(outlines[0].start(), outlines[0].end());
+
+        let inset = contour(&field, -2.0);
+        assert_eq!(inset.len(), 1);
+        let bounds = inset[0].bounds().unwrap();
+        assert!((bounds.width() - 12.0).abs() < 0.5, "{}", bounds.width());
+    }
+
+    #[test]
+    fn test_rounded_union() {
+        let a = Path::rectangle(point(0.0, 0.0), Vector::from(point(4.0, 4.0)));
+        let b = Path::rectangle(point(6.0, 0.0), Vector::from(point(4.0, 4.0)));
+        let field = DistanceField::from_paths(&[a, b], 40).unwrap();
+
+        assert_eq!(contour(&field, 0.0).len(), 2);
+        // grown by more than half the gap the squares merge
+        assert_eq!(contour(&field, 1.5).len(), 1);
+    }
+}
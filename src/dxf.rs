@@ -0,0 +1,479 @@
+use std::fmt::{Display, Write};
+
+use crate::{Float, Path, Point, Primitive, SweepArc, VectorValuedFn};
+
+/// writes paths as an AutoCAD 2000 (`AC1015`) DXF drawing for CNC and laser cutters
+///
+/// lines and flattened segments become `LWPOLYLINE`s,
+/// circular arcs are kept as `ARC` or `CIRCLE`
+/// and bezier curves as `SPLINE` entities,
+/// the drawing is flat so `z` coordinates are dropped
+///
+/// entities are placed on the layer named by the `"layer"` meta tag of their path,
+/// every layer is declared in the tables along with the other records
+/// strict readers expect, and all objects carry handles and subclass markers
+pub fn to_dxf(paths: &[Path]) -> String {
+    let mut layers = vec!["0"];
+    for path in paths {
+        let layer = path.get_meta("layer").unwrap_or("0");
+        if !layers.contains(&layer) {
+            layers.push(layer);
+        }
+    }
+
+    let mut dxf = Dxf::default();
+    dxf.tables(&layers);
+    dxf.blocks();
+    dxf.section("ENTITIES");
+    for path in paths {
+        dxf.path(path);
+    }
+    dxf.pair(0, "ENDSEC");
+    dxf.objects();
+    dxf.pair(0, "EOF");
+
+    let mut out = String::new();
+    write_pair(&mut out, 0, "SECTION");
+    write_pair(&mut out, 2, "HEADER");
+    write_pair(&mut out, 9, "$ACADVER");
+    write_pair(&mut out, 1, "AC1015");
+    write_pair(&mut out, 9, "$HANDSEED");
+    write_pair(&mut out, 5, format!("{:X}", dxf.handle + 1));
+    write_pair(&mut out, 0, "ENDSEC");
+    out + &dxf.out
+}
+
+/// sections after the header, handles are given out in order
+/// so the header can declare the next free one once everything is written
+#[derive(Default)]
+struct Dxf {
+    out: String,
+    handle: usize,
+    /// `*Model_Space` block record owning the blocks and entities
+    model_space: String,
+    paper_space: String,
+}
+
+impl Dxf {
+    fn pair(&mut self, code: u16, value: impl Display) {
+        write_pair(&mut self.out, code, value);
+    }
+
+    /// writes the next handle under `code`, `5` for everything but dimension styles
+    fn handle(&mut self, code: u16) -> String {
+        self.handle += 1;
+        let handle = format!("{:X}", self.handle);
+        self.pair(code, &handle);
+        handle
+    }
+
+    fn section(&mut self, name: &str) {
+        self.pair(0, "SECTION");
+        self.pair(2, name);
+    }
+
+    fn tables(&mut self, layers: &[&str]) {
+        self.section("TABLES");
+
+        for name in ["VPORT", "VIEW", "UCS"] {
+            self.table(name, 0);
+            self.pair(0, "ENDTAB");
+        }
+
+        let owner = self.table("LTYPE", 3);
+        for (name, description) in [
+            ("ByBlock", ""),
+            ("ByLayer", ""),
+            ("Continuous", "Solid line"),
+        ] {
+            self.record("LTYPE", &owner, "AcDbLinetypeTableRecord", name);
+            self.pair(3, description);
+            self.pair(72, 65);
+            self.pair(73, 0);
+            self.pair(40, 0.0);
+        }
+        self.pair(0, "ENDTAB");
+
+        let owner = self.table("LAYER", layers.len());
+        for layer in layers {
+            self.record("LAYER", &owner, "AcDbLayerTableRecord", layer);
+            self.pair(62, 7);
+            self.pair(6, "Continuous");
+        }
+        self.pair(0, "ENDTAB");
+
+        let owner = self.table("STYLE", 1);
+        self.record("STYLE", &owner, "AcDbTextStyleTableRecord", "Standard");
+        self.pair(40, 0.0);
+        self.pair(41, 1.0);
+        self.pair(50, 0.0);
+        self.pair(71, 0);
+        self.pair(42, 2.5);
+        self.pair(3, "txt");
+        self.pair(4, "");
+        self.pair(0, "ENDTAB");
+
+        let owner = self.table("APPID", 1);
+        self.record("APPID", &owner, "AcDbRegAppTableRecord", "ACAD");
+        self.pair(0, "ENDTAB");
+
+        let owner = self.table("DIMSTYLE", 1);
+        self.pair(100, "AcDbDimStyleTable");
+        self.pair(0, "DIMSTYLE");
+        self.handle(105);
+        self.pair(330, &owner);
+        self.pair(100, "AcDbSymbolTableRecord");
+        self.pair(100, "AcDbDimStyleTableRecord");
+        self.pair(2, "Standard");
+        self.pair(70, 0);
+        self.pair(0, "ENDTAB");
+
+        let owner = self.table("BLOCK_RECORD", 2);
+        self.model_space = self.record(
+            "BLOCK_RECORD",
+            &owner,
+            "AcDbBlockTableRecord",
+            "*Model_Space",
+        );
+        self.paper_space = self.record(
+            "BLOCK_RECORD",
+            &owner,
+            "AcDbBlockTableRecord",
+            "*Paper_Space",
+        );
+        self.pair(0, "ENDTAB");
+
+        self.pair(0, "ENDSEC");
+    }
+
+    /// opens a symbol table of `count` records and returns its handle
+    fn table(&mut self, name: &str, count: usize) -> String {
+        self.pair(0, "TABLE");
+        self.pair(2, name);
+        let handle = self.handle(5);
+        self.pair(330, 0);
+        self.pair(100, "AcDbSymbolTable");
+        self.pair(70, count);
+        handle
+    }
+
+    /// starts a symbol table record and returns its handle
+    fn record(&mut self, kind: &str, owner: &str, subclass: &str, name: &str) -> String {
+        self.pair(0, kind);
+        let handle = self.handle(5);
+        self.pair(330, owner);
+        self.pair(100, "AcDbSymbolTableRecord");
+        self.pair(100, subclass);
+        self.pair(2, name);
+        self.pair(70, 0);
+        handle
+    }
+
+    fn blocks(&mut self) {
+        self.section("BLOCKS");
+        for (name, owner) in [
+            ("*Model_Space", self.model_space.clone()),
+            ("*Paper_Space", self.paper_space.clone()),
+        ] {
+            let paper = name == "*Paper_Space";
+            self.pair(0, "BLOCK");
+            self.handle(5);
+            self.pair(330, &owner);
+            self.pair(100, "AcDbEntity");
+            if paper {
+                self.pair(67, 1);
+            }
+            self.pair(8, "0");
+            self.pair(100, "AcDbBlockBegin");
+            self.pair(2, name);
+            self.pair(70, 0);
+            self.pair(10, 0.0);
+            self.pair(20, 0.0);
+            self.pair(30, 0.0);
+            self.pair(3, name);
+            self.pair(1, "");
+
+            self.pair(0, "ENDBLK");
+            self.handle(5);
+            self.pair(330, &owner);
+            self.pair(100, "AcDbEntity");
+            if paper {
+                self.pair(67, 1);
+            }
+            self.pair(8, "0");
+            self.pair(100, "AcDbBlockEnd");
+        }
+        self.pair(0, "ENDSEC");
+    }
+
+    /// root dictionary with the empty group dictionary AutoCAD looks up
+    fn objects(&mut self) {
+        self.section("OBJECTS");
+        self.pair(0, "DICTIONARY");
+        let root = self.handle(5);
+        self.pair(330, 0);
+        self.pair(100, "AcDbDictionary");
+        self.pair(281, 1);
+        self.pair(3, "ACAD_GROUP");
+        self.pair(350, format!("{:X}", self.handle + 1));
+        self.pair(0, "DICTIONARY");
+        self.handle(5);
+        self.pair(330, &root);
+        self.pair(100, "AcDbDictionary");
+        self.pair(281, 1);
+        self.pair(0, "ENDSEC");
+    }
+
+    /// common start of a model space entity up to its own subclass marker
+    fn entity(&mut self, kind: &str, layer: &str, subclass: &str) {
+        self.pair(0, kind);
+        self.handle(5);
+        let owner = self.model_space.clone();
+        self.pair(330, owner);
+        self.pair(100, "AcDbEntity");
+        self.pair(8, layer);
+        self.pair(100, subclass);
+    }
+
+    fn path(&mut self, path: &Path) {
+        let layer = path.get_meta("layer").unwrap_or("0");
+        let mut polyline: Vec<[Float; 2]> = Vec::new();
+
+        for segment in path.segments() {
+            match segment.primitive() {
+                Some(Primitive::Line(line)) => {
+                    extend_polyline(&mut polyline, [line.start, line.end].map(xy));
+                }
+                Some(Primitive::Arc(arc)) if arc.radius.x == arc.radius.y => {
+                    self.polyline(layer, &mut polyline);
+                    self.arc(layer, &arc);
+                }
+                Some(Primitive::QuadraticCurve(c)) => {
+                    self.polyline(layer, &mut polyline);
+                    self.spline(layer, 2, &[c.start, c.control, c.end]);
+                }
+                Some(Primitive::CubicCurve(c)) => {
+                    self.polyline(layer, &mut polyline);
+                    self.spline(layer, 3, &[c.start, c.control1, c.control2, c.end]);
+                }
+                _ => {
+                    extend_polyline(
+                        &mut polyline,
+                        segment.sample_optimal().into_iter().map(|v| [v.x, v.y]),
+                    );
+                }
+            }
+        }
+
+        self.polyline(layer, &mut polyline);
+    }
+
+    /// writes and clears the pending polyline
+    fn polyline(&mut self, layer: &str, polyline: &mut Vec<[Float; 2]>) {
+        if polyline.len() < 2 {
+            polyline.clear();
+            return;
+        }
+
+        let closed = polyline.len() > 2 && polyline.first() == polyline.last();
+        if closed {
+            polyline.pop();
+        }
+
+        self.entity("LWPOLYLINE", layer, "AcDbPolyline");
+        self.pair(90, polyline.len());
+        self.pair(70, if closed { 1 } else { 0 });
+        for [x, y] in polyline.drain(..) {
+            self.pair(10, x);
+            self.pair(20, y);
+        }
+    }
+
+    fn arc(&mut self, layer: &str, arc: &SweepArc) {
+        let full_circle = arc.sweep_angle.to_radians() >= crate::Angle::TAU.to_radians();
+
+        self.entity(
+            if full_circle { "CIRCLE" } else { "ARC" },
+            layer,
+            "AcDbCircle",
+        );
+        self.pair(10, arc.center.x);
+        self.pair(20, arc.center.y);
+        self.pair(30, 0.0);
+        self.pair(40, arc.radius.x);
+        if !full_circle {
+            self.pair(100, "AcDbArc");
+            self.pair(50, arc.start_angle.to_degrees());
+            self.pair(51, (arc.start_angle + arc.sweep_angle).to_degrees());
+        }
+    }
+
+    fn spline(&mut self, layer: &str, degree: usize, control_points: &[Point]) {
+        self.entity("SPLINE", layer, "AcDbSpline");
+        self.pair(70, 8);
+        self.pair(71, degree);
+        self.pair(72, control_points.len() * 2);
+        self.pair(73, control_points.len());
+        self.pair(74, 0);
+        for knot in 0..control_points.len() * 2 {
+            self.pair(40, if knot < control_points.len() { 0 } else { 1 });
+        }
+        for [x, y] in control_points.iter().copied().map(xy) {
+            self.pair(10, x);
+            self.pair(20, y);
+            self.pair(30, 0.0);
+        }
+    }
+}
+
+fn extend_polyline(polyline: &mut Vec<[Float; 2]>, points: impl IntoIterator<Item = [Float; 2]>) {
+    for point in points {
+        if polyline.last() != Some(&point) {
+            polyline.push(point);
+        }
+    }
+}
+
+fn xy(p: Point) -> [Float; 2] {
+    [p.x, p.y]
+}
+
+fn write_pair(out: &mut String, code: u16, value: impl Display) {
+    writeln!(out, "{code}").unwrap();
+    writeln!(out, "{value}").unwrap();
+}
+
+#[cfg(test)]
+mod dxf_tests {
+    use super::*;
+    use crate::test_util::{point, vector};
+    use crate::{Angle, CubicCurve};
+
+    fn pairs(dxf: &str) -> Vec<(&str, &str)> {
+        let lines: Vec<&str> = dxf.lines().collect();
+        lines.chunks(2).map(|pair| (pair[0], pair[1])).collect()
+    }
+
+    /// types of the objects in the `ENTITIES` section
+    fn entities(dxf: &str) -> Vec<&str> {
+        let pairs = pairs(dxf);
+        let start = pairs
+            .iter()
+            .position(|pair| *pair == ("2", "ENTITIES"))
+            .unwrap();
+        pairs[start + 1..]
+            .iter()
+            .filter(|(code, _)| *code == "0")
+            .map(|(_, value)| *value)
+            .take_while(|value| *value != "ENDSEC")
+            .collect()
+    }
+
+    #[test]
+    fn test_rectangle_to_lwpolyline() {
+        let rect = Path::rectangle(point(0.0, 0.0), vector(2.0, 1.0)).with_meta("layer", "outline");
+        let dxf = to_dxf(&[rect]);
+
+        assert_eq!(entities(&dxf), vec!["LWPOLYLINE"]);
+        assert!(dxf.contains("90\n4\n70\n1\n"));
+        assert!(dxf.contains("8\noutline\n100\nAcDbPolyline\n"));
+    }
+
+    #[test]
+    fn test_arcs_and_curves_preserved() {
+        let path = Path::new(vec![
+            Box::new(SweepArc {
+                radius: vector(5.0, 5.0),
+                center: point(0.0, 0.0),
+                start_angle: Angle::ZERO,
+                sweep_angle: Angle::FRAC_PI_2,
+            }),
+            Box::new(CubicCurve {
+                start: point(0.0, 5.0),
+                control1: point(-1.0, 6.0),
+                control2: point(-2.0, 6.0),
+                end: point(-3.0, 5.0),
+            }),
+        ]);
+        let circle = Path::new(vec![Box::new(SweepArc::ellipse(
+            point(0.0, 0.0),
+            vector(1.0, 1.0),
+        ))]);
+        let dxf = to_dxf(&[path, circle]);
+
+        assert_eq!(entities(&dxf), vec!["ARC", "SPLINE", "CIRCLE"]);
+        assert!(dxf.contains("50\n0\n51\n90\n"));
+    }
+
+    #[test]
+    fn test_r2000_structure() {
+        let rect = Path::rectangle(point(0.0, 0.0), vector(2.0, 1.0)).with_meta("layer", "outline");
+        let arc = Path::new(vec![Box::new(SweepArc {
+            radius: vector(5.0, 5.0),
+            center: point(0.0, 0.0),
+            start_angle: Angle::ZERO,
+            sweep_angle: Angle::FRAC_PI_2,
+        })]);
+        let dxf = to_dxf(&[rect, arc]);
+        let pairs = pairs(&dxf);
+
+        let sections: Vec<&str> = pairs
+            .windows(2)
+            .filter(|w| w[0] == ("0", "SECTION"))
+            .map(|w| w[1].1)
+            .collect();
+        assert_eq!(
+            sections,
+            vec!["HEADER", "TABLES", "BLOCKS", "ENTITIES", "OBJECTS"]
+        );
+        for table in [
+            "LTYPE",
+            "LAYER",
+            "STYLE",
+            "APPID",
+            "DIMSTYLE",
+            "BLOCK_RECORD",
+        ] {
+            assert!(dxf.contains(&format!("0\nTABLE\n2\n{table}\n")));
+        }
+        for record in ["*Model_Space", "*Paper_Space", "outline", "Continuous"] {
+            assert!(pairs.contains(&("2", record)));
+        }
+
+        // every object after the header has a unique handle below the seed
+        let seed = pairs.iter().position(|p| *p == ("9", "$HANDSEED")).unwrap();
+        let seed = usize::from_str_radix(pairs[seed + 1].1, 16).unwrap();
+        let mut handles: Vec<usize> = pairs
+            .iter()
+            .filter(|(code, _)| *code == "5" || *code == "105")
+            .skip(1)
+            .map(|(_, h)| usize::from_str_radix(h, 16).unwrap())
+            .collect();
+        assert!(handles.iter().all(|h| *h < seed));
+        let count = handles.len();
+        handles.dedup();
+        assert_eq!(handles.len(), count);
+
+        let model_space = pairs
+            .windows(4)
+            .find(|w| w[0] == ("0", "BLOCK_RECORD") && w[3].1 == "AcDbSymbolTableRecord")
+            .map(|w| w[1].1)
+            .unwrap();
+        for (kind, subclasses) in [
+            ("LWPOLYLINE", vec!["AcDbEntity", "AcDbPolyline"]),
+            ("ARC", vec!["AcDbEntity", "AcDbCircle", "AcDbArc"]),
+        ] {
+            let start = pairs.iter().position(|p| *p == ("0", kind)).unwrap();
+            let end = start + 1 + pairs[start + 1..].iter().position(|p| p.0 == "0").unwrap();
+            let entity = &pairs[start..end];
+            assert_eq!(entity[1].0, "5");
+            assert_eq!(entity[2], ("330", model_space));
+            let markers: Vec<&str> = entity
+                .iter()
+                .filter(|(code, _)| *code == "100")
+                .map(|(_, value)| *value)
+                .collect();
+            assert_eq!(markers, subclasses);
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::{Angle, BBox, Float, Path, Point, Polyline};
+
+/// grid of directions covering `bounds` in the `xy` plane,
+/// directions between grid nodes are interpolated
+#[derive(Debug, Clone)]
+pub struct FlowField {
+    pub bounds: BBox,
+    pub columns: usize,
+    pub rows: usize,
+    /// row major, `columns * rows` angles
+    pub angles: Vec<Angle>,
+}
+
+impl FlowField {
+    /// samples `f` at every grid node
+    pub fn from_fn(bounds: BBox, columns: usize, rows: usize, f: impl Fn(Point) -> Angle) -> Self {
+        let (columns, rows) = (columns.max(2), rows.max(2));
+        let angles = (0..rows)
+            .flat_map(|r| (0..columns).map(move |c| (c, r)))
+            .map(|(c, r)| {
+                let mut p = bounds.min;
+                p.x += bounds.width() * c as Float / (columns - 1) as Float;
+                p.y += bounds.height() * r as Float / (rows - 1) as Float;
+                f(p)
+            })
+            .collect();
+
+        Self {
+            bounds,
+            columns,
+            rows,
+            angles,
+        }
+    }
+
+    /// smooth pseudo random field, `scale` is the size of a noise feature
+    pub fn noise(bounds: BBox, columns: usize, rows: usize, scale: Float, seed: u32) -> Self {
+        Self::from_fn(bounds, columns, rows, |p| {
+            Angle::from_radians(
+                value_noise(p.x / scale, p.y / scale, seed) * Angle::TAU.to_radians() * 2.0,
+            )
+        })
+    }
+
+    /// interpolated direction at `point`, `None` outside the bounds
+    pub fn direction_at(&self, point: Point) -> Option<[Float; 2]> {
+        let (w, h) = (self.bounds.width(), self.bounds.height());
+        let u = (point.x - self.bounds.min.x) / w * (self.columns - 1) as Float;
+        let v = (point.y - self.bounds.min.y) / h * (self.rows - 1) as Float;
+        if !(0.0..=(self.columns - 1) as Float).contains(&u)
+            || !(0.0..=(self.rows - 1) as Float).contains(&v)
+        {
+            return None;
+        }
+
+        let (c, r) = (
+            (u as usize).min(self.columns - 2),
+            (v as usize).min(self.rows - 2),
+        );
+        let (fu, fv) = (u - c as Float, v - r as Float);
+        let dir = |c: usize, r: usize| {
+            let a = self.angles[r * self.columns + c];
+            [a.cos(), a.sin()]
+        };
+        let lerp = |a: [Float; 2], b: [Float; 2], t: Float| {
+            [a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]
+        };
+
+        let d = lerp(
+            lerp(dir(c, r), dir(c + 1, r), fu),
+            lerp(dir(c, r + 1), dir(c + 1, r + 1), fu),
+            fv,
+        );
+        let len = d[0].hypot(d[1]);
+        (len > Float::EPSILON).then(|| [d[0] / len, d[1] / len])
+    }
+
+    /// follows the field from `start` for up to `steps` steps of `step` length,
+    /// stopping at the bounds or where the directions cancel out
+    ///
+    /// `None` when no step could be taken
+    pub fn streamline(&self, start: Point, step: Float, steps: usize) -> Option<Path> {
+        let mut points = vec![start];
+        let mut current = start;
+
+        for _ in 0..steps {
+            // midpoint method keeps lines from drifting off tight curls
+            let Some(d0) = self.direction_at(current) else {
+                break;
+            };
+            let mut mid = current;
+            mid.x += d0[0] * step / 2.0;
+            mid.y += d0[1] * step / 2.0;
+            let Some(d) = self.direction_at(mid) else {
+                break;
+            };
+
+            let mut next = current;
+            next.x += d[0] * step;
+            next.y += d[1] * step;
+            if !self.bounds.contains(next) {
+                break;
+            }
+            points.push(next);
+            current = next;
+        }
+
+        (points.len() > 1).then(|| Polyline(points).into())
+    }
+
+    /// streamlines from every seed point
+    pub fn streamlines(
+        &self,
+        seeds: impl IntoIterator<Item = Point>,
+        step: Float,
+        steps: usize,
+    ) -> Vec<Path> {
+        seeds
+            .into_iter()
+            .filter_map(|seed| self.streamline(seed, step, steps))
+            .collect()
+    }
+}
+
+/// bilinear value noise in `0..1` over an integer lattice
+fn value_noise(x: Float, y: Float, seed: u32) -> Float {
+    let (x0, y0) = (x.floor(), y.floor());
+    let (fx, fy) = (x - x0, y - y0);
+    let smooth = |t: Float| t * t * (3.0 - 2.0 * t);
+    let (sx, sy) = (smooth(fx), smooth(fy));
+    let at = |dx: i32, dy: i32| lattice(x0 as i32 + dx, y0 as i32 + dy, seed);
+
+    let top = at(0, 0) + (at(1, 0) - at(0, 0)) * sx;
+    let bottom = at(0, 1) + (at(1, 1) - at(0, 1)) * sx;
+    top + (bottom - top) * sy
+}
+
+/// pseudo random value from 0 to 1 for a lattice node
+pub(crate) fn lattice(x: i32, y: i32, seed: u32) -> Float {
+    let mut h = (x as u32).wrapping_mul(0x8da6_b343)
+        ^ (y as u32).wrapping_mul(0xd816_3841)
+        ^ seed.wrapping_mul(0xcb1a_b31f);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0x5bd1_e995);
+    h ^= h >> 15;
+    h as Float / u32::MAX as Float
+}
+
+#[cfg(test)]
+mod flow_field_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::VectorValuedFn;
+
+    fn bounds() -> BBox {
+        BBox::new(point(0.0, 0.0), point(10.0, 10.0))
+    }
+
+    #[test]
+    fn test_uniform_field() {
+        let field = FlowField::from_fn(bounds(), 4, 4, |_| Angle::ZERO);
+        let line = field.streamline(point(1.0, 5.0), 1.0, 100).unwrap();
+        assert!((line.end().x - 10.0).abs() <= 1.0);
+        assert!((line.end().y - 5.0).abs() < 1e-4);
+        assert!(field.streamline(point(20.0, 5.0), 1.0, 10).is_none());
+    }
+
+    #[test]
+    fn test_circular_field() {
+        let center = point(5.0, 5.0);
+        let field = FlowField::from_fn(bounds(), 41, 41, |p| {
+            Angle::from_radians((p.y - center.y).atan2(p.x - center.x)) + Angle::FRAC_PI_2
+        });
+        let line = field.streamline(point(8.0, 5.0), 0.1, 150).unwrap();
+        for sample in line.sample_evenly(20) {
+            let r = (sample.x - 5.0).hypot(sample.y - 5.0);
+            assert!((r - 3.0).abs() < 0.1, "{r}");
+        }
+    }
+
+    #[test]
+    fn test_noise_field() {
+        let a = FlowField::noise(bounds(), 8, 8, 3.0, 7);
+        let b = FlowField::noise(bounds(), 8, 8, 3.0, 7);
+        let c = FlowField::noise(bounds(), 8, 8, 3.0, 8);
+        assert_eq!(a.angles, b.angles);
+        assert_ne!(a.angles, c.angles);
+
+        let seeds = (1..10).map(|i| point(i as Float, 5.0));
+        assert!(!a.streamlines(seeds, 0.5, 20).is_empty());
+    }
+}
@@ -0,0 +1,78 @@
+use crate::{
+    affine_rotation, affine_translation, Angle, Float, GlVec, Path, Point, Trochoid, Vector,
+};
+
+/// rosette engine pattern filling the annulus between `r_inner` and `r_outer`
+///
+/// each strand is a hypotrochoid with `lobes` lobes swinging from the inner
+/// to the outer radius, the `strands` copies are turned by even phase offsets
+/// spread over one lobe so that together they weave a banknote style band
+pub fn guilloche(
+    center: Point,
+    r_inner: Float,
+    r_outer: Float,
+    lobes: usize,
+    strands: usize,
+) -> Vec<Path> {
+    let (r_inner, r_outer) = (r_inner.min(r_outer).max(0.0), r_inner.max(r_outer));
+    let lobes = lobes.max(2);
+    if strands == 0 || r_outer <= 0.0 {
+        return Vec::new();
+    }
+
+    // the pen swings by `distance` around the rolling circle center
+    // which itself travels on the middle radius
+    let middle = (r_inner + r_outer) / 2.0;
+    let distance = (r_outer - r_inner) / 2.0;
+    let rolling_radius = middle / (lobes - 1) as Float;
+    let strand = Trochoid {
+        center,
+        fixed_radius: rolling_radius * lobes as Float,
+        rolling_radius,
+        distance,
+        outside: false,
+        turns: 1.0,
+    };
+
+    let offset = GlVec::from(center);
+    let phase = Angle::TAU / (lobes * strands) as Float;
+    (0..strands)
+        .map(|i| {
+            let turn = affine_translation(offset.into())
+                * affine_rotation(phase * i as Float)
+                * affine_translation(Vector::from(-offset));
+            Path::new(vec![Box::new(strand)]).transformed(turn)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod guilloche_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::VectorValuedFn;
+
+    #[test]
+    fn test_guilloche_fits_annulus() {
+        let strands = guilloche(point(1.0, 2.0), 3.0, 5.0, 12, 4);
+        assert_eq!(strands.len(), 4);
+
+        for strand in &strands {
+            let radii: Vec<Float> = strand
+                .samples_optimal()
+                .map(|v| (v.x - 1.0).hypot(v.y - 2.0))
+                .collect();
+            assert!(radii.iter().all(|r| *r > 2.99 && *r < 5.01));
+            assert!(radii.iter().any(|r| *r > 4.9));
+            assert!(radii.iter().any(|r| *r < 3.1));
+            assert!(GlVec::from(strand.start()).distance(strand.end().into()) < 1e-3);
+        }
+        assert!(GlVec::from(strands[0].start()).distance(strands[1].start().into()) > 0.1);
+    }
+
+    #[test]
+    fn test_guilloche_degenerate() {
+        assert!(guilloche(point(1.0, 2.0), 3.0, 5.0, 12, 0).is_empty());
+        assert_eq!(guilloche(point(1.0, 2.0), 5.0, 3.0, 1, 1).len(), 1);
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{ring_signed_area, Angle, Float, LineSegment, Path, Point, Polyline, VectorValuedFn};
+
+/// planar math for the outlines whatever the dimension
+#[cfg(feature = "f64")]
+type GlVec2 = glam::DVec2;
+#[cfg(feature = "f32")]
+type GlVec2 = glam::Vec2;
+
+/// parallel lines at `angle` every `spacing` clipped to the closed outline,
+/// one path per stroke
+///
+/// the outline is flattened in the `xy` plane, crossings are counted even-odd
+/// so self-overlapping outlines leave holes
+pub fn hatch(outline: &Path, spacing: Float, angle: Angle) -> Vec<Path> {
+    let ring = flat_ring(outline);
+    if ring.len() < 3 || spacing <= 0.0 {
+        return Vec::new();
+    }
+
+    let (sin, cos) = (angle.sin(), angle.cos());
+    let to_local = |p: [Float; 2]| [p[0] * cos + p[1] * sin, -p[0] * sin + p[1] * cos];
+    let to_world = |p: [Float; 2]| [p[0] * cos - p[1] * sin, p[0] * sin + p[1] * cos];
+
+    let local: Vec<[Float; 2]> = ring.iter().map(|p| to_local(*p)).collect();
+    let (min_y, max_y) = local.iter().fold((Float::MAX, Float::MIN), |(lo, hi), p| {
+        (lo.min(p[1]), hi.max(p[1]))
+    });
+
+    let base = outline.start();
+    let mut strokes = Vec::new();
+    let mut y = min_y + spacing / 2.0;
+    while y < max_y {
+        let mut crossings: Vec<Float> = local
+            .iter()
+            .zip(local.iter().cycle().skip(1))
+            .filter(|(a, b)| (a[1] <= y) != (b[1] <= y))
+            .map(|(a, b)| a[0] + (y - a[1]) / (b[1] - a[1]) * (b[0] - a[0]))
+            .collect();
+        crossings.sort_by(|a, b| a.total_cmp(b));
+
+        for pair in crossings.chunks_exact(2) {
+            let start = to_world([pair[0], y]);
+            let end = to_world([pair[1], y]);
+            strokes.push(Path::new(vec![Box::new(LineSegment {
+                start: point(start, base),
+                end: point(end, base),
+            })]));
+        }
+        y += spacing;
+    }
+
+    strokes
+}
+
+/// two sets of [`hatch`] lines, the second turned by a quarter turn
+pub fn cross_hatch(outline: &Path, spacing: Float, angle: Angle) -> Vec<Path> {
+    let mut strokes = hatch(outline, spacing, angle);
+    strokes.extend(hatch(outline, spacing, angle + Angle::FRAC_PI_2));
+    strokes
+}
+
+/// outlines shrunk by `spacing` step by step until they vanish,
+/// each a closed path
+///
+/// edges are offset with mitered corners,
+/// so deeply concave outlines may produce crossing rings
+pub fn contour_hatch(outline: &Path, spacing: Float) -> Vec<Path> {
+    let mut ring = flat_ring(outline);
+    if ring.len() < 3 || spacing <= 0.0 {
+        return Vec::new();
+    }
+
+    let orientation = ring_signed_area(ring.iter().copied()).signum();
+    let base = outline.start();
+    let mut rings = Vec::new();
+
+    loop {
+        let inset = offset_ring(&ring, spacing * orientation);
+        let area = ring_signed_area(inset.iter().copied());
+        if area * orientation <= 0.0 || area.abs() >= ring_signed_area(ring.iter().copied()).abs() {
+            break;
+        }
+
+        let mut points: Vec<Point> = inset.iter().map(|p| point(*p, base)).collect();
+        points.push(points[0]);
+        rings.push(Polyline(points).into());
+        ring = inset;
+    }
+
+    rings
+}
+
+/// point in the plane of `base`
+fn point(p: [Float; 2], base: Point) -> Point {
+    let mut point = base;
+    point.x = p[0];
+    point.y = p[1];
+    point
+}
+
+/// flattened outline without the closing point
+fn flat_ring(outline: &Path) -> Vec<[Float; 2]> {
+    let mut ring: Vec<[Float; 2]> = outline.samples_optimal().map(|v| [v.x, v.y]).collect();
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    ring
+}
+
+/// moves every edge `distance` to its left and joins neighbours at their intersection
+fn offset_ring(ring: &[[Float; 2]], distance: Float) -> Vec<[Float; 2]> {
+    let n = ring.len();
+    let edge = |i: usize| {
+        let (a, b) = (GlVec2::from(ring[i]), GlVec2::from(ring[(i + 1) % n]));
+        let normal = (b - a).perp().normalize_or_zero() * distance;
+        (a + normal, b - a)
+    };
+
+    (0..n)
+        .map(|i| {
+            let (p0, d0) = edge((i + n - 1) % n);
+            let (p1, d1) = edge(i);
+            let denom = d0.perp_dot(d1);
+            let joint = if denom.abs() <= Float::EPSILON {
+                p1
+            } else {
+                p0 + d0 * ((p1 - p0).perp_dot(d1) / denom)
+            };
+            joint.into()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod hatch_tests {
+    use super::*;
+    use crate::test_util::{point, vector};
+    use crate::BBox;
+
+    fn square() -> Path {
+        Path::rectangle(point(0.0, 0.0), vector(10.0, 10.0))
+    }
+
+    #[test]
+    fn test_hatch_square() {
+        let strokes = hatch(&square(), 1.0, Angle::ZERO);
+        assert_eq!(strokes.len(), 10);
+        for (i, stroke) in strokes.iter().enumerate() {
+            assert!((stroke.length() - 10.0).abs() < 1e-4);
+            assert!((stroke.start().y - (i as Float + 0.5)).abs() < 1e-4);
+        }
+
+        let diagonal = hatch(&square(), 1.0, Angle::FRAC_PI_4);
+        let outline = square().bounds().unwrap().expanded(1e-3);
+        assert!(diagonal
+            .iter()
+            .all(|s| outline.contains(s.start()) && outline.contains(s.end())));
+        assert_eq!(cross_hatch(&square(), 1.0, Angle::ZERO).len(), 20);
+    }
+
+    #[test]
+    fn test_contour_hatch() {
+        let rings = contour_hatch(&square(), 2.0);
+        assert_eq!(rings.len(), 2);
+
+        let first = rings[0].bounds().unwrap();
+        assert!((first.min.x - 2.0).abs() < 1e-4 && (first.max.x - 8.0).abs() < 1e-4);
+        let last: BBox = rings[1].bounds().unwrap();
+        assert!((last.width() - 2.0).abs() < 1e-4);
+    }
+}
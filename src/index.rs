@@ -0,0 +1,214 @@
+use crate::{pick::distance_to_segment, BBox, Float, Path, PickResult, Point, VectorValuedFn};
+
+/// uniform grid over flattened path segments
+/// for fast area queries and picking
+pub struct PathIndex {
+    bounds: BBox,
+    cell_size: Float,
+    columns: usize,
+    rows: usize,
+    cells: Vec<Vec<usize>>,
+    items: Vec<IndexedSegment>,
+}
+
+struct IndexedSegment {
+    path_index: usize,
+    segment_index: usize,
+    bbox: BBox,
+    polyline: Vec<[Float; 2]>,
+}
+
+impl PathIndex {
+    /// flattens and indexes every segment of the paths
+    pub fn new(paths: &[Path]) -> Self {
+        let items: Vec<IndexedSegment> = paths
+            .iter()
+            .enumerate()
+            .flat_map(|(path_index, path)| {
+                path.segments()
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(segment_index, segment)| {
+                        let samples = segment.sample_optimal();
+                        let polyline = samples.iter().map(|v| [v.x, v.y]).collect();
+                        BBox::from_points(samples).map(|bbox| IndexedSegment {
+                            path_index,
+                            segment_index,
+                            bbox,
+                            polyline,
+                        })
+                    })
+            })
+            .collect();
+
+        let bounds = items
+            .iter()
+            .map(|i| i.bbox)
+            .reduce(|a, b| {
+                BBox::from_points([a.min.into(), a.max.into(), b.min.into(), b.max.into()]).unwrap()
+            })
+            .unwrap_or(BBox::new(
+                crate::GlVec::ZERO.into(),
+                crate::GlVec::ZERO.into(),
+            ));
+
+        let cell_size = (bounds.width().max(bounds.height())
+            / (items.len() as Float).sqrt().max(1.0))
+        .max(Float::EPSILON);
+        let columns = (bounds.width() / cell_size) as usize + 1;
+        let rows = (bounds.height() / cell_size) as usize + 1;
+
+        let mut index = Self {
+            bounds,
+            cell_size,
+            columns,
+            rows,
+            cells: vec![Vec::new(); columns * rows],
+            items: Vec::new(),
+        };
+
+        for (id, item) in items.iter().enumerate() {
+            let (c0, r0, c1, r1) = index.cell_range(&item.bbox);
+            for r in r0..=r1 {
+                for c in c0..=c1 {
+                    index.cells[r * columns + c].push(id);
+                }
+            }
+        }
+        index.items = items;
+
+        index
+    }
+
+    /// bounds of all indexed segments
+    pub fn bounds(&self) -> BBox {
+        self.bounds
+    }
+
+    /// `(path_index, segment_index)` of segments whose bounds intersect `area`
+    pub fn query(&self, area: &BBox) -> Vec<(usize, usize)> {
+        let mut found: Vec<(usize, usize)> = self
+            .candidates(area)
+            .into_iter()
+            .map(|id| &self.items[id])
+            .filter(|item| item.bbox.intersects(area))
+            .map(|item| (item.path_index, item.segment_index))
+            .collect();
+        found.sort_unstable();
+        found
+    }
+
+    /// same as [`crate::pick`] but only tests segments near the point
+    pub fn pick(&self, point: Point, tolerance: Float) -> Option<PickResult> {
+        let p = [point.x, point.y];
+        let area = BBox::new(point, point).expanded(tolerance);
+        let mut best: Option<PickResult> = None;
+
+        for id in self.candidates(&area) {
+            let item = &self.items[id];
+            let distance = item
+                .polyline
+                .windows(2)
+                .map(|w| distance_to_segment(p, w[0], w[1]))
+                .fold(Float::INFINITY, Float::min);
+
+            let on_top = best.is_none_or(|b| {
+                distance < b.distance
+                    || (distance == b.distance
+                        && (item.path_index, item.segment_index) > (b.path_index, b.segment_index))
+            });
+
+            if distance <= tolerance && on_top {
+                best = Some(PickResult {
+                    path_index: item.path_index,
+                    segment_index: item.segment_index,
+                    distance,
+                });
+            }
+        }
+
+        best
+    }
+
+    fn candidates(&self, area: &BBox) -> Vec<usize> {
+        if self.items.is_empty()
+            || area.max.x < self.bounds.min.x
+            || area.max.y < self.bounds.min.y
+            || area.min.x > self.bounds.max.x
+            || area.min.y > self.bounds.max.y
+        {
+            return Vec::new();
+        }
+
+        let (c0, r0, c1, r1) = self.cell_range(area);
+        let mut ids: Vec<usize> = (r0..=r1)
+            .flat_map(|r| (c0..=c1).map(move |c| r * self.columns + c))
+            .flat_map(|cell| self.cells[cell].iter().copied())
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
+    }
+
+    fn cell_range(&self, bbox: &BBox) -> (usize, usize, usize, usize) {
+        let cell = |v: Float, min: Float, count: usize| {
+            (((v - min) / self.cell_size).max(0.0) as usize).min(count - 1)
+        };
+
+        (
+            cell(bbox.min.x, self.bounds.min.x, self.columns),
+            cell(bbox.min.y, self.bounds.min.y, self.rows),
+            cell(bbox.max.x, self.bounds.min.x, self.columns),
+            cell(bbox.max.y, self.bounds.min.y, self.rows),
+        )
+    }
+}
+
+#[cfg(test)]
+mod index_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{pick, Vector};
+
+    fn grid() -> Vec<Path> {
+        (0..10)
+            .flat_map(|i| {
+                (0..10).map(move |j| {
+                    Path::rectangle(
+                        point(i as Float * 10.0, j as Float * 10.0),
+                        Vector::from(point(5.0, 5.0)),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_query() {
+        let index = PathIndex::new(&grid());
+        let area = BBox::new(point(11.0, 11.0), point(14.0, 14.0));
+        assert!(index.query(&area).is_empty());
+
+        let area = BBox::new(point(11.0, 14.0), point(14.0, 16.0));
+        assert_eq!(index.query(&area), vec![(11, 2)]);
+
+        let area = BBox::new(point(-10.0, -10.0), point(-5.0, -5.0));
+        assert!(index.query(&area).is_empty());
+    }
+
+    #[test]
+    fn test_pick_matches_brute_force() {
+        let paths = grid();
+        let index = PathIndex::new(&paths);
+
+        for (x, y) in [
+            (12.0, 15.5),
+            (50.0, 50.0),
+            (97.0, 0.5),
+            (200.0, 0.0),
+            (35.0, 47.0),
+        ] {
+            assert_eq!(index.pick(point(x, y), 1.0), pick(&paths, point(x, y), 1.0));
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashSet;
+
+use crate::{Angle, Float, Path, Point, Polyline};
+
+/// share of a step cut away on both sides of a crossing passed under
+const UNDER_GAP: Float = 0.3;
+
+/// samples per step between crossings, so ring bands follow the curvature
+const STEP_SAMPLES: usize = 8;
+
+/// interlaced bands with the weave split into groups
+///
+/// `over` holds the visible runs of every band, each broken where the band
+/// passes under another, `under` holds the short pieces hidden at those
+/// crossings so styling can leave them out or draw them fainter
+#[derive(Default)]
+pub struct Interlace {
+    pub over: Vec<Path>,
+    pub under: Vec<Path>,
+}
+
+/// Celtic plait over `cols` by `rows` cells of `cell` size from `origin`
+///
+/// bands run diagonally through the cell edges, bounce off the border
+/// and alternate over and under at every crossing along their way
+pub fn knot_grid(origin: Point, cell: Float, cols: usize, rows: usize) -> Interlace {
+    let half = cell / 2.0;
+    interlace(cols, rows, false, |x, y| Point {
+        x: origin.x + x * half,
+        y: origin.y + y * half,
+        #[cfg(feature = "3d")]
+        z: origin.z,
+    })
+}
+
+/// Celtic plait wrapped around the annulus between `r_inner` and `r_outer`,
+/// `cols` cells around and `rows` cells across
+pub fn knot_ring(
+    center: Point,
+    r_inner: Float,
+    r_outer: Float,
+    cols: usize,
+    rows: usize,
+) -> Interlace {
+    let around = Angle::TAU.to_radians() / (cols.max(1) * 2) as Float;
+    let across = (r_outer - r_inner) / (rows.max(1) * 2) as Float;
+    interlace(cols, rows, true, |x, y| {
+        let (angle, radius) = (x * around, r_inner + y * across);
+        Point {
+            x: center.x + radius * angle.cos(),
+            y: center.y + radius * angle.sin(),
+            #[cfg(feature = "3d")]
+            z: center.z,
+        }
+    })
+}
+
+/// traces the plait on a lattice of half cells,
+/// `map` places lattice coordinates in space
+fn interlace(
+    cols: usize,
+    rows: usize,
+    wrap: bool,
+    map: impl Fn(Float, Float) -> Point,
+) -> Interlace {
+    let mut weave = Interlace::default();
+    if cols == 0 || rows == 0 {
+        return weave;
+    }
+
+    let (width, height) = (cols as i64 * 2, rows as i64 * 2);
+    let mut visited = HashSet::new();
+    // bands only pass lattice points with an odd coordinate sum,
+    // so they never hit a corner and every inner one is a crossing
+    for x in (1..width).step_by(2) {
+        for dx in [1, -1] {
+            let start = (x, 0, dx, 1);
+            if visited.contains(&start) {
+                continue;
+            }
+
+            let mut band = Vec::new();
+            let mut state = start;
+            loop {
+                // the same band traced backwards leaves the bottom border mirrored
+                visited.insert(state);
+                if state.1 == 0 {
+                    visited.insert((state.0, 0, -state.2, 1));
+                }
+                band.push(state);
+                let (mut x, mut y, mut dx, mut dy) = state;
+                x += dx;
+                y += dy;
+                if wrap {
+                    x = x.rem_euclid(width);
+                } else if x == 0 || x == width {
+                    dx = -dx;
+                }
+                if y == 0 || y == height {
+                    dy = -dy;
+                }
+                state = (x, y, dx, dy);
+                if state == start {
+                    break;
+                }
+            }
+
+            // the diagonal going up and right passes over at even columns,
+            // which alternates along every band and across bounces
+            let under: Vec<bool> = band
+                .iter()
+                .map(|&(x, y, dx, dy)| {
+                    let border = y == 0 || y == height || (!wrap && (x == 0 || x == width));
+                    !border && (dx == dy) != (x % 2 == 0)
+                })
+                .collect();
+            weave_band(&band, &under, &map, &mut weave);
+        }
+    }
+
+    weave
+}
+
+/// splits one closed band at the crossings it passes under
+fn weave_band(
+    band: &[(i64, i64, i64, i64)],
+    under: &[bool],
+    map: &impl Fn(Float, Float) -> Point,
+    weave: &mut Interlace,
+) {
+    let mut runs: Vec<Vec<Point>> = vec![Vec::new()];
+    let mut hidden: Vec<Point> = Vec::new();
+    for (i, &(x, y, dx, dy)) in band.iter().enumerate() {
+        // lattice step taken before wrapping or bouncing
+        let at = |t: Float| map(x as Float + dx as Float * t, y as Float + dy as Float * t);
+        let sample = |points: &mut Vec<Point>, from: Float, to: Float| {
+            let first = if points.is_empty() { 0 } else { 1 };
+            points.extend(
+                (first..=STEP_SAMPLES)
+                    .map(|k| at(from + (to - from) * k as Float / STEP_SAMPLES as Float)),
+            );
+        };
+
+        let mut begin = 0.0;
+        if under[i] {
+            sample(&mut hidden, 0.0, UNDER_GAP);
+            weave
+                .under
+                .push(Polyline(std::mem::take(&mut hidden)).into());
+            begin = UNDER_GAP;
+        }
+
+        let next_under = under[(i + 1) % band.len()];
+        let end = if next_under { 1.0 - UNDER_GAP } else { 1.0 };
+        sample(runs.last_mut().unwrap(), begin, end);
+
+        if next_under {
+            runs.push(Vec::new());
+            sample(&mut hidden, end, 1.0);
+        }
+    }
+
+    // bands start on the border, so the last run continues into the first
+    if runs.len() > 1 {
+        let mut last = runs.pop().unwrap();
+        last.pop();
+        last.append(&mut runs[0]);
+        runs[0] = last;
+    }
+    weave
+        .over
+        .extend(runs.into_iter().map(|run| Path::from(Polyline(run))));
+}
+
+#[cfg(test)]
+mod knot_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{GlVec, VectorValuedFn};
+
+    #[test]
+    fn test_knot_grid() {
+        let weave = knot_grid(point(0.0, 0.0), 2.0, 3, 2);
+
+        // inner lattice points with an odd coordinate sum on the 6 by 4 lattice,
+        // each crossed twice with one band passing under
+        let crossings = (1..6)
+            .flat_map(|x| (1..4).map(move |y| (x, y)))
+            .filter(|(x, y)| (x + y) % 2 == 1)
+            .count();
+        assert_eq!(weave.under.len(), crossings);
+        assert_eq!(weave.over.len(), crossings);
+
+        for path in weave.over.iter().chain(weave.under.iter()) {
+            for v in path.samples_optimal() {
+                assert!(v.x >= -1e-4 && v.x <= 6.0001 && v.y >= -1e-4 && v.y <= 4.0001);
+            }
+        }
+        for piece in &weave.under {
+            let middle = GlVec::from(piece.eval(0.5));
+            assert!(middle.distance(middle.round()) < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_knot_ring() {
+        let weave = knot_ring(point(0.0, 0.0), 3.0, 5.0, 6, 1);
+
+        // bands cross on the middle circle once per cell
+        assert_eq!(weave.under.len(), 6);
+        assert_eq!(weave.over.len(), 6);
+        for path in weave.over.iter().chain(weave.under.iter()) {
+            for v in path.samples_optimal() {
+                let r = v.x.hypot(v.y);
+                assert!(r > 2.999 && r < 5.001);
+            }
+        }
+        for piece in &weave.under {
+            let middle = piece.eval(0.5);
+            assert!((middle.x.hypot(middle.y) - 4.0).abs() < 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_empty_knot() {
+        let weave = knot_grid(point(0.0, 0.0), 1.0, 0, 3);
+        assert!(weave.over.is_empty() && weave.under.is_empty());
+    }
+}
@@ -1,14 +1,97 @@
 mod angle;
+mod animate;
+mod bbox;
+mod border;
+mod brush;
+#[cfg(feature = "3d")]
+mod camera;
+mod canvas;
+mod clip;
+mod contour;
+mod dxf;
+mod flow_field;
+mod guilloche;
+mod hatch;
+#[cfg(feature = "index")]
+mod index;
+mod knot;
+#[cfg(feature = "3d")]
+mod mesh;
+mod motif;
 mod paths;
+mod penrose;
+mod pick;
+mod plotter;
+mod predicates;
 mod primitives;
+mod proportions;
+#[cfg(feature = "reaction-diffusion")]
+mod reaction;
+#[cfg(feature = "test-render")]
+mod render_test;
+mod sdf;
+#[cfg(feature = "3d")]
+mod sphere;
+mod stats;
+mod stipple;
+mod stroke;
+mod tessellate;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "trace")]
+mod trace;
 mod transform;
 mod vector_valued;
+mod warp;
+#[cfg(feature = "wfc")]
+mod wfc;
+mod wgsl;
 
 pub use angle::*;
+pub use animate::*;
+pub use bbox::*;
+pub use border::*;
+pub use brush::*;
+#[cfg(feature = "3d")]
+pub use camera::*;
+pub use canvas::*;
+pub use clip::*;
+pub use contour::*;
+pub use dxf::*;
+pub use flow_field::*;
+pub use guilloche::*;
+pub use hatch::*;
+#[cfg(feature = "index")]
+pub use index::*;
+pub use knot::*;
+#[cfg(feature = "3d")]
+pub use mesh::*;
+pub use motif::*;
 pub use paths::*;
+pub use penrose::*;
+pub use pick::*;
+pub use plotter::*;
+pub use predicates::*;
 pub use primitives::*;
+pub use proportions::*;
+#[cfg(feature = "reaction-diffusion")]
+pub use reaction::*;
+#[cfg(feature = "test-render")]
+pub use render_test::*;
+pub use sdf::*;
+#[cfg(feature = "3d")]
+pub use sphere::*;
+pub use stats::*;
+pub use stipple::*;
+pub use stroke::*;
+pub use tessellate::*;
+#[cfg(feature = "trace")]
+pub use trace::*;
 pub use transform::*;
 pub use vector_valued::*;
+pub use warp::*;
+#[cfg(feature = "wfc")]
+pub use wfc::*;
 
 #[cfg(test)]
 pub(crate) mod test_util {
@@ -24,4 +107,24 @@ pub(crate) mod test_util {
     pub fn test_name(name: &str) -> String {
         format!("{FEAT}-{name}")
     }
+
+    /// point on the `xy` plane in either `2d` or `3d`
+    pub fn point(x: crate::Float, y: crate::Float) -> crate::Point {
+        crate::Point {
+            x,
+            y,
+            #[cfg(feature = "3d")]
+            z: 0.0,
+        }
+    }
+
+    /// vector on the `xy` plane in either `2d` or `3d`
+    pub fn vector(x: crate::Float, y: crate::Float) -> crate::Vector {
+        crate::Vector {
+            x,
+            y,
+            #[cfg(feature = "3d")]
+            z: 0.0,
+        }
+    }
 }
@@ -0,0 +1,246 @@
+use std::fmt::Write;
+
+use crate::{
+    ring_signed_area, transform_point, triangulate, Affine, Angle, Float, GlVec, Mesh, Point,
+    Vector, VectorValuedFn,
+};
+
+/// segments of a revolution over a full turn
+const REVOLVE_STEPS_PER_TURN: usize = 64;
+
+impl Mesh {
+    /// unit normal of a triangle
+    pub fn normal(&self, triangle: [usize; 3]) -> Vector {
+        let [a, b, c] = triangle.map(|i| GlVec::from(self.vertices[i]));
+        (b - a).cross(c - a).normalize_or_zero().into()
+    }
+
+    /// Wavefront OBJ text
+    pub fn to_obj(&self) -> String {
+        let mut out = String::new();
+        for v in &self.vertices {
+            writeln!(out, "v {} {} {}", v.x, v.y, v.z).unwrap();
+        }
+        for [a, b, c] in &self.triangles {
+            writeln!(out, "f {} {} {}", a + 1, b + 1, c + 1).unwrap();
+        }
+        out
+    }
+
+    /// ASCII STL text
+    pub fn to_stl(&self, name: &str) -> String {
+        let mut out = format!("solid {name}\n");
+        for triangle in &self.triangles {
+            let n = self.normal(*triangle);
+            writeln!(out, "facet normal {} {} {}", n.x, n.y, n.z).unwrap();
+            out.push_str("outer loop\n");
+            for i in triangle {
+                let v = self.vertices[*i];
+                writeln!(out, "vertex {} {} {}", v.x, v.y, v.z).unwrap();
+            }
+            out.push_str("endloop\nendfacet\n");
+        }
+        writeln!(out, "endsolid {name}").unwrap();
+        out
+    }
+}
+
+/// extrudes the flattened path along `z` by `height`,
+/// closed paths get a bottom and a top cap
+pub fn extrude<F: VectorValuedFn + ?Sized>(path: &F, height: Float) -> Mesh {
+    let mut points: Vec<GlVec> = path.sample_optimal().into_iter().map(GlVec::from).collect();
+    let closed = points.len() > 2 && points[0].distance(points[points.len() - 1]) <= Float::EPSILON;
+    if closed {
+        points.pop();
+    }
+
+    let n = points.len();
+    let mut mesh = Mesh::default();
+    if n < 2 {
+        return mesh;
+    }
+
+    let up = GlVec::Z * height;
+    mesh.vertices.extend(points.iter().map(|p| Point::from(*p)));
+    mesh.vertices
+        .extend(points.iter().map(|p| Point::from(*p + up)));
+
+    // outward normals for counter-clockwise outlines extruded upwards
+    let flip = (ring_signed_area(points.iter().map(|p| [p.x, p.y])) < 0.0) != (height < 0.0);
+    let edges = if closed { n } else { n - 1 };
+    for i in 0..edges {
+        let j = (i + 1) % n;
+        let quad = [[i, j, n + j], [i, n + j, n + i]];
+        for [a, b, c] in quad {
+            mesh.triangles
+                .push(if flip { [a, c, b] } else { [a, b, c] });
+        }
+    }
+
+    if closed {
+        // triangulation is counter-clockwise whatever the outline direction
+        for [a, b, c] in triangulate(&points) {
+            if height < 0.0 {
+                mesh.triangles.push([a, b, c]);
+                mesh.triangles.push([n + a, n + c, n + b]);
+            } else {
+                mesh.triangles.push([a, c, b]);
+                mesh.triangles.push([n + a, n + b, n + c]);
+            }
+        }
+    }
+
+    mesh
+}
+
+/// sweeps the flattened profile around `axis` through the origin by `sweep` radians,
+/// negative sweeps turn clockwise and a full turn or more closes the surface
+///
+/// takes radians rather than an [`Angle`], which would wrap a full turn to nothing
+pub fn revolve<F: VectorValuedFn + ?Sized>(profile: &F, axis: Vector, sweep: Float) -> Mesh {
+    let points: Vec<GlVec> = profile
+        .sample_optimal()
+        .into_iter()
+        .map(GlVec::from)
+        .collect();
+    let axis = GlVec::from(axis).normalize_or_zero();
+    let mut mesh = Mesh::default();
+    if points.len() < 2 || axis == GlVec::ZERO {
+        return mesh;
+    }
+
+    let tau = Angle::TAU.to_radians();
+    let sweep = sweep.clamp(-tau, tau);
+    let turns = sweep.abs() / tau;
+    let full = turns >= 1.0 - Float::EPSILON;
+    let steps = ((turns * REVOLVE_STEPS_PER_TURN as Float).ceil() as usize).max(1);
+    let rings = if full { steps } else { steps + 1 };
+
+    let n = points.len();
+    for ring in 0..rings {
+        let angle = sweep * ring as Float / steps as Float;
+        let rotation = Affine::from_axis_angle(axis, angle);
+        mesh.vertices.extend(
+            points
+                .iter()
+                .map(|p| Point::from(transform_point(&rotation, (*p).into()))),
+        );
+    }
+
+    let flip = sweep < 0.0;
+    for ring in 0..steps {
+        let (r0, r1) = (ring * n, ((ring + 1) % rings) * n);
+        for i in 0..n - 1 {
+            let quad = [
+                [r0 + i, r0 + i + 1, r1 + i + 1],
+                [r0 + i, r1 + i + 1, r1 + i],
+            ];
+            for [a, b, c] in quad {
+                mesh.triangles
+                    .push(if flip { [a, b, c] } else { [a, c, b] });
+            }
+        }
+    }
+
+    mesh
+}
+
+#[cfg(test)]
+mod mesh_tests {
+    use super::*;
+    use crate::{LineSegment, Path};
+
+    fn point(x: Float, y: Float, z: Float) -> Point {
+        Point { x, y, z }
+    }
+
+    /// sum of signed tetrahedron volumes, positive for outward facing triangles
+    fn volume(mesh: &Mesh) -> Float {
+        mesh.triangles
+            .iter()
+            .map(|t| {
+                let [a, b, c] = t.map(|i| GlVec::from(mesh.vertices[i]));
+                a.dot(b.cross(c)) / 6.0
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_extrude_closed() {
+        let square = Path::rectangle(
+            point(0.0, 0.0, 0.0),
+            Vector {
+                x: 2.0,
+                y: 3.0,
+                z: 0.0,
+            },
+        );
+        let mesh = extrude(&square, 4.0);
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.triangles.len(), 12);
+        assert!((volume(&mesh) - 24.0).abs() < 1e-3);
+
+        let mut reversed = Path::rectangle(
+            point(0.0, 0.0, 0.0),
+            Vector {
+                x: 2.0,
+                y: 3.0,
+                z: 0.0,
+            },
+        );
+        reversed.reverse();
+        assert!((volume(&extrude(&reversed, 4.0)) - 24.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_extrude_open() {
+        let wall = LineSegment {
+            start: point(0.0, 0.0, 0.0),
+            end: point(1.0, 0.0, 0.0),
+        };
+        let mesh = extrude(&wall, 1.0);
+        assert_eq!(mesh.triangles.len(), 2);
+    }
+
+    #[test]
+    fn test_revolve_cylinder() {
+        let side = LineSegment {
+            start: point(1.0, 0.0, 0.0),
+            end: point(1.0, 0.0, 2.0),
+        };
+        let axis = Vector {
+            x: 0.0,
+            y: 0.0,
+            z: 1.0,
+        };
+        for sweep in [360.0, -360.0, 720.0] {
+            let full = revolve(&side, axis, (sweep as Float).to_radians());
+            assert_eq!(full.vertices.len(), 2 * REVOLVE_STEPS_PER_TURN);
+            assert_eq!(full.triangles.len(), 2 * REVOLVE_STEPS_PER_TURN);
+            for triangle in &full.triangles {
+                let n = GlVec::from(full.normal(*triangle));
+                let c = GlVec::from(full.vertices[triangle[0]]);
+                assert!(n.dot(GlVec::new(c.x, c.y, 0.0)) > 0.0);
+            }
+        }
+
+        let half = revolve(&side, axis, Angle::PI.to_radians());
+        assert_eq!(half.vertices.len(), 2 * (REVOLVE_STEPS_PER_TURN / 2 + 1));
+    }
+
+    #[test]
+    fn test_mesh_export() {
+        let mesh = Mesh {
+            vertices: vec![
+                point(0.0, 0.0, 0.0),
+                point(1.0, 0.0, 0.0),
+                point(0.0, 1.0, 0.0),
+            ],
+            triangles: vec![[0, 1, 2]],
+        };
+        assert_eq!(mesh.to_obj(), "v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n");
+        let stl = mesh.to_stl("tri");
+        assert!(stl.starts_with("solid tri\nfacet normal 0 0 1\nouter loop\n"));
+        assert!(stl.ends_with("endloop\nendfacet\nendsolid tri\n"));
+    }
+}
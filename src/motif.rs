@@ -0,0 +1,334 @@
+use crate::{
+    Angle, CubicCurve, Float, GlVec, Path, PathSegment, Point, Polyline, SweepArc, Vector,
+};
+
+/// closed radial shape symmetric under `symmetry` rotations,
+/// each sector mirrored about its middle
+///
+/// `radii` describe half a sector from its edge to its middle
+/// and are relative to the motif radius
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Motif {
+    pub symmetry: usize,
+    pub radii: Vec<Float>,
+}
+
+impl Motif {
+    pub fn new(symmetry: usize, radii: Vec<Float>) -> Self {
+        Self {
+            symmetry: symmetry.max(1),
+            radii,
+        }
+    }
+
+    /// random motif with up to `complexity` control radii per half sector
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::Rng + ?Sized>(rng: &mut R, complexity: usize) -> Self {
+        let complexity = complexity.max(1);
+        let symmetry = rng.gen_range(1..=complexity.min(8));
+        let n = rng.gen_range(1..=complexity) + 1;
+        let radii = (0..n).map(|_| rng.gen_range(0.35..=1.0)).collect();
+        Self::new(symmetry, radii)
+    }
+
+    /// control points of the outline in order, counter-clockwise from the `x` axis
+    fn outline(&self, center: Point, radius: Float) -> Vec<GlVec> {
+        let half: &[Float] = &self.radii;
+        if half.is_empty() {
+            return Vec::new();
+        }
+
+        // edge to middle, then back without repeating the middle or the next edge
+        let sector: Vec<Float> = half
+            .iter()
+            .chain(half.iter().rev().skip(1).take(half.len().saturating_sub(2)))
+            .copied()
+            .collect();
+        let step = Angle::TAU.to_radians() / (self.symmetry * sector.len()) as Float;
+
+        (0..self.symmetry)
+            .flat_map(|_| sector.iter())
+            .enumerate()
+            .map(|(i, r)| {
+                let angle = step * i as Float;
+                let mut p = GlVec::from(center);
+                p.x += radius * r * angle.cos();
+                p.y += radius * r * angle.sin();
+                p
+            })
+            .collect()
+    }
+
+    /// closed smooth path through the outline points of the motif
+    pub fn to_path(&self, center: Point, radius: Float) -> Path {
+        closed_spline(&self.outline(center, radius))
+    }
+}
+
+/// declarative drawing made of layers, all sizes relative to the motif radius
+/// and angles in turns
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MotifSpec {
+    pub layers: Vec<MotifLayer>,
+}
+
+/// one layer of a [`MotifSpec`]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum MotifLayer {
+    /// arc at `radius` covering `sweep` turns, centered on the `x` axis
+    Arc { radius: Float, sweep: Float },
+    /// closed wavy ring of `count` rounded lobes reaching `depth` past `radius`
+    Lobes {
+        count: usize,
+        radius: Float,
+        depth: Float,
+    },
+    /// closed ring of `count` pointed teeth reaching `depth` past `radius`
+    Teeth {
+        count: usize,
+        radius: Float,
+        depth: Float,
+    },
+    /// `count` circles of `size` radius evenly spaced on a ring of `radius`
+    Dots {
+        count: usize,
+        radius: Float,
+        size: Float,
+    },
+    /// a nested [`Motif`]
+    Motif(Motif),
+}
+
+impl MotifSpec {
+    /// paths of every layer in order
+    pub fn to_paths(&self, center: Point, radius: Float) -> Vec<Path> {
+        self.layers
+            .iter()
+            .flat_map(|layer| layer.to_paths(center, radius))
+            .collect()
+    }
+}
+
+impl MotifLayer {
+    pub fn to_paths(&self, center: Point, scale: Float) -> Vec<Path> {
+        let turn = Angle::TAU.to_radians();
+        let polar = |r: Float, angle: Float| {
+            let mut p = GlVec::from(center);
+            p.x += scale * r * angle.cos();
+            p.y += scale * r * angle.sin();
+            p
+        };
+        let circle = |c: Point, r: Float| {
+            let radius = GlVec::splat(r);
+            #[cfg(feature = "3d")]
+            let radius = radius.with_z(0.0);
+            SweepArc::ellipse(c, Vector::from(radius))
+        };
+
+        match self {
+            MotifLayer::Arc { radius, sweep } => {
+                let mut arc = circle(center, scale * radius);
+                if *sweep < 1.0 {
+                    arc.start_angle = Angle::from_radians(-sweep * turn / 2.0);
+                    arc.sweep_angle = Angle::from_radians(sweep * turn);
+                }
+                vec![Path::new(vec![Box::new(arc)])]
+            }
+            MotifLayer::Lobes {
+                count,
+                radius,
+                depth,
+            } => {
+                let n = 2 * (*count).max(1);
+                let points: Vec<GlVec> = (0..n)
+                    .map(|i| {
+                        let r = if i % 2 == 0 { *radius } else { radius + depth };
+                        polar(r, turn * i as Float / n as Float)
+                    })
+                    .collect();
+                vec![closed_spline(&points)]
+            }
+            MotifLayer::Teeth {
+                count,
+                radius,
+                depth,
+            } => {
+                let n = 2 * (*count).max(1);
+                let mut points: Vec<Point> = (0..=n)
+                    .map(|i| {
+                        let r = if i % 2 == 0 { *radius } else { radius + depth };
+                        polar(r, turn * i as Float / n as Float).into()
+                    })
+                    .collect();
+                points[n] = points[0];
+                vec![Polyline(points).into()]
+            }
+            MotifLayer::Dots {
+                count,
+                radius,
+                size,
+            } => (0..*count)
+                .map(|i| {
+                    let c = polar(*radius, turn * i as Float / *count as Float);
+                    Path::new(vec![Box::new(circle(c.into(), scale * size))])
+                })
+                .collect(),
+            MotifLayer::Motif(motif) => vec![motif.to_path(center, scale)],
+        }
+    }
+}
+
+/// draws the motif for a stamp index around the origin,
+/// implemented for closures so simple renderers stay one-liners
+/// while stateful ones can keep caches or loaded shapes
+pub trait MotifRenderer {
+    fn render(&mut self, index: usize) -> Vec<Path>;
+}
+
+impl<F: FnMut(usize) -> Vec<Path>> MotifRenderer for F {
+    fn render(&mut self, index: usize) -> Vec<Path> {
+        self(index)
+    }
+}
+
+impl MotifRenderer for Box<dyn MotifRenderer> {
+    fn render(&mut self, index: usize) -> Vec<Path> {
+        self.as_mut().render(index)
+    }
+}
+
+/// renders the same spec for every index with a unit radius
+impl MotifRenderer for MotifSpec {
+    fn render(&mut self, _: usize) -> Vec<Path> {
+        self.to_paths(GlVec::ZERO.into(), 1.0)
+    }
+}
+
+impl MotifRenderer for Motif {
+    fn render(&mut self, _: usize) -> Vec<Path> {
+        vec![self.to_path(GlVec::ZERO.into(), 1.0)]
+    }
+}
+
+/// closed Catmull-Rom spline through the points as cubic Bézier segments
+pub(crate) fn closed_spline(points: &[GlVec]) -> Path {
+    let n = points.len();
+    if n < 2 {
+        return Path::default();
+    }
+
+    Path::new(
+        (0..n)
+            .map(|i| {
+                let p0 = points[(i + n - 1) % n];
+                let p1 = points[i];
+                let p2 = points[(i + 1) % n];
+                let p3 = points[(i + 2) % n];
+                Box::new(CubicCurve {
+                    start: p1.into(),
+                    control1: (p1 + (p2 - p0) / 6.0).into(),
+                    control2: (p2 - (p3 - p1) / 6.0).into(),
+                    end: p2.into(),
+                }) as PathSegment
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod motif_tests {
+    use super::*;
+    use crate::VectorValuedFn;
+
+    fn origin() -> Point {
+        GlVec::ZERO.into()
+    }
+
+    fn radius_at(path: &Path, t: Float) -> Float {
+        let p = path.eval(t);
+        p.x.hypot(p.y)
+    }
+
+    #[test]
+    fn test_motif_is_closed_and_symmetric() {
+        let motif = Motif::new(4, vec![0.5, 1.0, 0.7]);
+        let path = motif.to_path(origin(), 10.0);
+        assert_eq!(path.segments().len(), 4 * 4);
+        assert_eq!(path.start(), path.end());
+
+        // rotational symmetry, every quarter looks the same
+        for i in 0..8 {
+            let t = i as Float / 32.0;
+            assert!((radius_at(&path, t) - radius_at(&path, t + 0.25)).abs() < 1e-3);
+        }
+
+        // mirror symmetry about the middle of the sector
+        let start = path.eval(0.0);
+        let mirrored = path.eval(0.25);
+        assert!((start.x - mirrored.y).abs() < 1e-3 && (start.y - mirrored.x).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_motif_spec() {
+        let spec = MotifSpec {
+            layers: vec![
+                MotifLayer::Arc {
+                    radius: 1.0,
+                    sweep: 0.25,
+                },
+                MotifLayer::Lobes {
+                    count: 6,
+                    radius: 0.6,
+                    depth: 0.2,
+                },
+                MotifLayer::Teeth {
+                    count: 8,
+                    radius: 0.3,
+                    depth: 0.1,
+                },
+                MotifLayer::Dots {
+                    count: 5,
+                    radius: 0.9,
+                    size: 0.05,
+                },
+                MotifLayer::Motif(Motif::new(3, vec![0.1, 0.2])),
+            ],
+        };
+        let paths = spec.to_paths(origin(), 10.0);
+        assert_eq!(paths.len(), 1 + 1 + 1 + 5 + 1);
+
+        let arc = &paths[0];
+        assert!((radius_at(arc, 0.5) - 10.0).abs() < 1e-3);
+        assert!((arc.start().y + arc.end().y).abs() < 1e-3);
+
+        let lobes = &paths[1];
+        assert_eq!(lobes.segments().len(), 12);
+        assert!((radius_at(lobes, 0.0) - 6.0).abs() < 1e-3);
+
+        let teeth = &paths[2];
+        assert_eq!(teeth.start(), teeth.end());
+        assert!(teeth.bounds().unwrap().width() <= 8.0 + 1e-3);
+
+        assert!((radius_at(&paths[3], 0.0) - 9.5).abs() < 1e-3);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_motif() {
+        use rand::SeedableRng;
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(42);
+        for _ in 0..10 {
+            let motif = Motif::random(&mut rng, 5);
+            assert!((1..=5).contains(&motif.symmetry));
+            assert!(motif.radii.iter().all(|r| (0.35..=1.0).contains(r)));
+
+            let path = motif.to_path(origin(), 1.0);
+            assert!(path.bounds().unwrap().width() <= 2.5);
+        }
+    }
+}
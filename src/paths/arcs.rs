@@ -1,4 +1,7 @@
-use crate::{Angle, GlVec, Point, Vector, VectorValuedFn};
+use crate::{quadrature_length, Angle, Float, GlVec, Point, Primitive, Vector, VectorValuedFn};
+
+/// relative error allowed in arc lengths, coarse enough for `f32`
+const ARC_LENGTH_TOLERANCE: Float = 1e-5;
 
 /// sweeps an arc of radius with center, start and sweep angles
 #[derive(Debug, Clone, Copy)]
@@ -18,6 +21,12 @@ impl SweepArc {
             sweep_angle: Angle::TAU,
         }
     }
+
+    /// arc length within `tolerance` relative to the length,
+    /// [`VectorValuedFn::length`] uses `1e-5`
+    pub fn length_with_tolerance(&self, tolerance: Float) -> Float {
+        quadrature_length(self, tolerance)
+    }
 }
 
 impl Default for SweepArc {
@@ -44,7 +53,23 @@ impl VectorValuedFn for SweepArc {
     }
 
     fn length(&self) -> crate::Float {
-        self.radius.x.hypot(self.radius.y) * self.sweep_angle.to_radians()
+        self.length_with_tolerance(ARC_LENGTH_TOLERANCE)
+    }
+
+    fn derivative(&self, t: crate::Float) -> Vector {
+        let angle = self.start_angle + self.sweep_angle * t;
+        let sweep = self.sweep_angle.to_radians();
+
+        crate::Vector {
+            x: -sweep * self.radius.x * angle.sin(),
+            y: sweep * self.radius.y * angle.cos(),
+            #[cfg(feature = "3d")]
+            z: sweep * self.radius.z * angle.cos(),
+        }
+    }
+
+    fn primitive(&self) -> Option<Primitive> {
+        Some(Primitive::Arc(*self))
     }
 }
 
@@ -124,11 +149,15 @@ impl ArcSegment {
         }
         .into()
     }
-}
 
-impl VectorValuedFn for ArcSegment {
-    fn eval(&self, t: crate::Float) -> Vector {
-        let center = self.arc_center();
+    /// arc length within `tolerance` relative to the length,
+    /// [`VectorValuedFn::length`] uses `1e-5`
+    pub fn length_with_tolerance(&self, tolerance: Float) -> Float {
+        quadrature_length(self, tolerance)
+    }
+
+    /// center, start and sweep angles, shared by `eval` and `derivative`
+    fn sweep(&self) -> (Point, Angle, Angle) {
         #[cfg(feature = "3d")]
         let rad = (GlVec::from(self.end) - GlVec::from(self.start))
             .angle_between(GlVec::from(self.radius));
@@ -136,14 +165,19 @@ impl VectorValuedFn for ArcSegment {
         let rad =
             (GlVec::from(self.end) - GlVec::from(self.start)).angle_to(GlVec::from(self.radius));
 
-        let start_angle = Angle::from_radians(rad);
-
         let sweep_angle = if self.large_arc {
             Angle::PI
         } else {
             Angle::FRAC_PI_2
         };
 
+        (self.arc_center(), Angle::from_radians(rad), sweep_angle)
+    }
+}
+
+impl VectorValuedFn for ArcSegment {
+    fn eval(&self, t: crate::Float) -> Vector {
+        let (center, start_angle, sweep_angle) = self.sweep();
         let angle = start_angle + sweep_angle * t;
 
         crate::Vector {
@@ -155,29 +189,27 @@ impl VectorValuedFn for ArcSegment {
     }
 
     fn length(&self) -> crate::Float {
-        #[cfg(feature = "3d")]
-        let rad = (GlVec::from(self.end) - GlVec::from(self.start))
-            .angle_between(GlVec::from(self.radius));
-        #[cfg(feature = "2d")]
-        let rad =
-            (GlVec::from(self.end) - GlVec::from(self.start)).angle_to(GlVec::from(self.radius));
-
-        let start_angle = Angle::from_radians(rad);
+        self.length_with_tolerance(ARC_LENGTH_TOLERANCE)
+    }
 
-        let sweep_angle = if self.large_arc {
-            Angle::PI
-        } else {
-            Angle::FRAC_PI_2
-        };
+    fn derivative(&self, t: crate::Float) -> Vector {
+        let (_, start_angle, sweep_angle) = self.sweep();
+        let angle = start_angle + sweep_angle * t;
+        let sweep = sweep_angle.to_radians();
 
-        (start_angle + sweep_angle).to_radians() * self.radius.x.hypot(self.radius.y)
+        crate::Vector {
+            x: -sweep * self.radius.x * angle.sin(),
+            y: sweep * self.radius.y * angle.cos(),
+            #[cfg(feature = "3d")]
+            z: sweep * self.radius.z * angle.cos(),
+        }
     }
 }
 
 #[cfg(test)]
 mod arc_tests {
     use super::*;
-    use crate::test_util::test_name;
+    use crate::test_util::{point, test_name, vector};
     use insta::assert_debug_snapshot;
 
     #[test]
@@ -230,6 +262,55 @@ mod arc_tests {
         assert_debug_snapshot!(test_name("segment-arc"), points);
     }
 
+    #[test]
+    fn test_arc_lengths() {
+        let center = point(3.0, -2.0);
+        let tau = Angle::TAU.to_radians();
+        let close = |a: Float, b: Float| (a - b).abs() / b < 1e-4;
+
+        for r in [0.5, 10.0, 250.0] {
+            let circle = SweepArc::ellipse(center, vector(r, r));
+            assert!(close(circle.length(), tau * r));
+
+            let quarter = SweepArc {
+                start_angle: Angle::from_degrees(30.0),
+                sweep_angle: Angle::FRAC_PI_2,
+                ..circle
+            };
+            assert!(close(quarter.length(), tau * r / 4.0));
+        }
+
+        // Ramanujan's approximation is far more precise than the tolerance here
+        let (a, b) = (10.0, 4.0);
+        let h = ((a - b) / (a + b)) * ((a - b) / (a + b));
+        let perimeter = tau / 2.0 * (a + b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()));
+        let ellipse = SweepArc::ellipse(center, vector(a, b));
+        assert!(close(ellipse.length(), perimeter));
+        assert!((ellipse.length_with_tolerance(1e-2) - perimeter).abs() / perimeter < 1e-2);
+    }
+
+    #[test]
+    fn test_arc_segment_length() {
+        let arc = ArcSegment {
+            start: point(0.0, 10.0),
+            end: point(10.0, 0.0),
+            radius: vector(10.0, 10.0),
+            large_arc: true,
+            poz_angle: true,
+        };
+        let pi = Angle::PI.to_radians();
+        let close = |a: Float, b: Float| (a - b).abs() / b < 1e-4;
+
+        // half a turn for the large arc and a quarter for the small one
+        assert!(close(arc.length(), pi * 10.0));
+        let small = ArcSegment {
+            large_arc: false,
+            ..arc
+        };
+        assert!(close(small.length(), pi * 5.0));
+        assert!(close(small.length(), crate::sampled_length(&small)));
+    }
+
     #[test]
     fn test_sweep_arc_methods() {
         let arc = SweepArc {
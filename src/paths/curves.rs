@@ -1,6 +1,6 @@
 use cfg_if::cfg_if;
 
-use crate::{GlVec, Point, VectorValuedFn};
+use crate::{GlVec, Point, Primitive, VectorValuedFn};
 
 /// Quadratic Bezier curve with one control point
 #[derive(Debug, Clone, Copy)]
@@ -51,6 +51,10 @@ impl VectorValuedFn for QuadraticCurve {
         }
         length
     }
+
+    fn primitive(&self) -> Option<Primitive> {
+        Some(Primitive::QuadraticCurve(*self))
+    }
 }
 
 /// Cubic Bezier curve with two control points
@@ -107,6 +111,10 @@ impl VectorValuedFn for CubicCurve {
         }
         length
     }
+
+    fn primitive(&self) -> Option<Primitive> {
+        Some(Primitive::CubicCurve(*self))
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,543 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{sampled_length, Float, Vector, VectorValuedFn};
+
+/// curve defined by math expressions parsed at runtime
+///
+/// the source holds statements separated by new lines or `;`,
+/// `x(t) = ...`, `y(t) = ...` and in 3d `z(t) = ...` define the coordinates,
+/// any other `name = ...` defines a parameter that later statements can use,
+/// parameters can't depend on `t` and follow the ones they're defined with
+/// when those are [`ExprCurve::set`]
+///
+/// e.g. `r = 10; x(t) = r*cos(t*TAU); y(t) = r*sin(t*TAU)`
+///
+/// expressions support `+ - * / ^`, parentheses, the constants `PI`, `TAU` and `E`
+/// and the functions `sin cos tan asin acos atan atan2 sqrt abs exp ln pow min max floor ceil fract clamp mix`,
+/// coordinates that aren't defined are `0`
+#[derive(Debug, Clone)]
+pub struct ExprCurve {
+    /// current value of every parameter, a redefinition shadows the earlier one
+    params: Vec<(String, Float)>,
+    definitions: Vec<Expr>,
+    x: Expr,
+    y: Expr,
+    #[cfg(feature = "3d")]
+    z: Expr,
+}
+
+/// reason an [`ExprCurve`] source was rejected
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprError {
+    /// character that can't start a token
+    UnexpectedChar(char, usize),
+    /// token that doesn't fit the grammar at that position
+    UnexpectedToken(String, usize),
+    UnexpectedEnd,
+    UnknownName(String),
+    /// function called with the wrong number of arguments
+    Arity(String, usize),
+    /// statement without `=`
+    MissingAssignment(String),
+    /// parameter defined in terms of `t`
+    VaryingParameter(String),
+}
+
+impl fmt::Display for ExprError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedChar(c, at) => write!(f, "unexpected character `{c}` at {at}"),
+            Self::UnexpectedToken(token, at) => write!(f, "unexpected `{token}` at {at}"),
+            Self::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            Self::UnknownName(name) => write!(f, "unknown name `{name}`"),
+            Self::Arity(name, n) => write!(f, "`{name}` takes {n} argument(s)"),
+            Self::MissingAssignment(statement) => {
+                write!(f, "expected `name = expression` in `{statement}`")
+            }
+            Self::VaryingParameter(name) => write!(f, "parameter `{name}` depends on `t`"),
+        }
+    }
+}
+
+impl std::error::Error for ExprError {}
+
+impl ExprCurve {
+    pub fn parse(source: &str) -> Result<Self, ExprError> {
+        let mut params: Vec<(String, Float)> = Vec::new();
+        let mut definitions = Vec::new();
+        let mut coordinates: HashMap<&str, Expr> = HashMap::new();
+
+        for statement in source
+            .split([';', '\n'])
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+        {
+            let (name, body) = statement
+                .split_once('=')
+                .ok_or_else(|| ExprError::MissingAssignment(statement.to_string()))?;
+            let name = name.trim();
+            let expr = Parser::new(body, &params)?.parse()?;
+
+            match name {
+                "x(t)" | "y(t)" | "z(t)" => {
+                    coordinates.insert(&name[..1], expr);
+                }
+                _ if is_name(name) => {
+                    if expr.uses_t() {
+                        return Err(ExprError::VaryingParameter(name.to_string()));
+                    }
+                    params.push((name.to_string(), expr.eval(0.0, &params)));
+                    definitions.push(expr);
+                }
+                _ => return Err(ExprError::UnknownName(name.to_string())),
+            }
+        }
+
+        let mut take = |c| coordinates.remove(c).unwrap_or(Expr::Num(0.0));
+
+        Ok(Self {
+            x: take("x"),
+            y: take("y"),
+            #[cfg(feature = "3d")]
+            z: take("z"),
+            params,
+            definitions,
+        })
+    }
+
+    /// current value of a parameter
+    pub fn get(&self, name: &str) -> Option<Float> {
+        self.params
+            .iter()
+            .rfind(|(n, _)| n == name)
+            .map(|(_, v)| *v)
+    }
+
+    /// changes a parameter defined in the source without parsing it again,
+    /// parameters defined after it are evaluated again
+    pub fn set(&mut self, name: &str, value: Float) -> Result<(), ExprError> {
+        let index = self
+            .params
+            .iter()
+            .rposition(|(n, _)| n == name)
+            .ok_or_else(|| ExprError::UnknownName(name.to_string()))?;
+        self.definitions[index] = Expr::Num(value);
+        for i in index..self.params.len() {
+            self.params[i].1 = self.definitions[i].eval(0.0, &self.params);
+        }
+        Ok(())
+    }
+}
+
+impl VectorValuedFn for ExprCurve {
+    fn eval(&self, t: Float) -> Vector {
+        Vector {
+            x: self.x.eval(t, &self.params),
+            y: self.y.eval(t, &self.params),
+            #[cfg(feature = "3d")]
+            z: self.z.eval(t, &self.params),
+        }
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Expr {
+    Num(Float),
+    T,
+    Param(usize),
+    Neg(Box<Expr>),
+    Binary(char, Box<Expr>, Box<Expr>),
+    Call(Func, Vec<Expr>),
+}
+
+impl Expr {
+    fn uses_t(&self) -> bool {
+        match self {
+            Expr::Num(_) | Expr::Param(_) => false,
+            Expr::T => true,
+            Expr::Neg(e) => e.uses_t(),
+            Expr::Binary(_, a, b) => a.uses_t() || b.uses_t(),
+            Expr::Call(_, args) => args.iter().any(Expr::uses_t),
+        }
+    }
+
+    fn eval(&self, t: Float, params: &[(String, Float)]) -> Float {
+        match self {
+            Expr::Num(v) => *v,
+            Expr::T => t,
+            Expr::Param(i) => params[*i].1,
+            Expr::Neg(e) => -e.eval(t, params),
+            Expr::Binary(op, a, b) => {
+                let (a, b) = (a.eval(t, params), b.eval(t, params));
+                match op {
+                    '+' => a + b,
+                    '-' => a - b,
+                    '*' => a * b,
+                    '/' => a / b,
+                    _ => a.powf(b),
+                }
+            }
+            Expr::Call(func, args) => {
+                let a: Vec<Float> = args.iter().map(|e| e.eval(t, params)).collect();
+                func.apply(&a)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Func {
+    Sin,
+    Cos,
+    Tan,
+    Asin,
+    Acos,
+    Atan,
+    Atan2,
+    Sqrt,
+    Abs,
+    Exp,
+    Ln,
+    Pow,
+    Min,
+    Max,
+    Floor,
+    Ceil,
+    Fract,
+    Clamp,
+    Mix,
+}
+
+impl Func {
+    fn lookup(name: &str) -> Option<(Self, usize)> {
+        Some(match name {
+            "sin" => (Self::Sin, 1),
+            "cos" => (Self::Cos, 1),
+            "tan" => (Self::Tan, 1),
+            "asin" => (Self::Asin, 1),
+            "acos" => (Self::Acos, 1),
+            "atan" => (Self::Atan, 1),
+            "atan2" => (Self::Atan2, 2),
+            "sqrt" => (Self::Sqrt, 1),
+            "abs" => (Self::Abs, 1),
+            "exp" => (Self::Exp, 1),
+            "ln" => (Self::Ln, 1),
+            "pow" => (Self::Pow, 2),
+            "min" => (Self::Min, 2),
+            "max" => (Self::Max, 2),
+            "floor" => (Self::Floor, 1),
+            "ceil" => (Self::Ceil, 1),
+            "fract" => (Self::Fract, 1),
+            "clamp" => (Self::Clamp, 3),
+            "mix" => (Self::Mix, 3),
+            _ => return None,
+        })
+    }
+
+    fn apply(self, a: &[Float]) -> Float {
+        match self {
+            Self::Sin => a[0].sin(),
+            Self::Cos => a[0].cos(),
+            Self::Tan => a[0].tan(),
+            Self::Asin => a[0].asin(),
+            Self::Acos => a[0].acos(),
+            Self::Atan => a[0].atan(),
+            Self::Atan2 => a[0].atan2(a[1]),
+            Self::Sqrt => a[0].sqrt(),
+            Self::Abs => a[0].abs(),
+            Self::Exp => a[0].exp(),
+            Self::Ln => a[0].ln(),
+            Self::Pow => a[0].powf(a[1]),
+            Self::Min => a[0].min(a[1]),
+            Self::Max => a[0].max(a[1]),
+            Self::Floor => a[0].floor(),
+            Self::Ceil => a[0].ceil(),
+            Self::Fract => a[0].fract(),
+            // never panics on reversed or NaN bounds, unlike `Float::clamp`
+            Self::Clamp => a[0].max(a[1]).min(a[2]),
+            Self::Mix => a[0] + (a[1] - a[0]) * a[2],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Num(Float),
+    Name(String),
+    Op(char),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Num(v) => write!(f, "{v}"),
+            Token::Name(n) => write!(f, "{n}"),
+            Token::Op(c) => write!(f, "{c}"),
+        }
+    }
+}
+
+fn is_name(s: &str) -> bool {
+    let mut chars = s.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn tokenize(source: &str) -> Result<Vec<(Token, usize)>, ExprError> {
+    let mut tokens = Vec::new();
+    let chars: Vec<char> = source.chars().collect();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            let text: String = chars[start..i].iter().collect();
+            let value = text
+                .parse()
+                .map_err(|_| ExprError::UnexpectedToken(text.clone(), start))?;
+            tokens.push((Token::Num(value), start));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].is_ascii_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push((Token::Name(chars[start..i].iter().collect()), start));
+        } else if "+-*/^(),".contains(c) {
+            tokens.push((Token::Op(c), start));
+            i += 1;
+        } else {
+            return Err(ExprError::UnexpectedChar(c, start));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// recursive descent parser with the usual precedence,
+/// `^` binds tighter than unary minus and is right associative
+struct Parser<'p> {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    params: &'p [(String, Float)],
+}
+
+impl<'p> Parser<'p> {
+    fn new(source: &str, params: &'p [(String, Float)]) -> Result<Self, ExprError> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            pos: 0,
+            params,
+        })
+    }
+
+    fn parse(mut self) -> Result<Expr, ExprError> {
+        let expr = self.sum()?;
+        match self.tokens.get(self.pos) {
+            Some((token, at)) => Err(ExprError::UnexpectedToken(token.to_string(), *at)),
+            None => Ok(expr),
+        }
+    }
+
+    fn peek_op(&self) -> Option<char> {
+        match self.tokens.get(self.pos) {
+            Some((Token::Op(c), _)) => Some(*c),
+            _ => None,
+        }
+    }
+
+    fn expect(&mut self, op: char) -> Result<(), ExprError> {
+        match self.tokens.get(self.pos) {
+            Some((Token::Op(c), _)) if *c == op => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some((token, at)) => Err(ExprError::UnexpectedToken(token.to_string(), *at)),
+            None => Err(ExprError::UnexpectedEnd),
+        }
+    }
+
+    fn sum(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.product()?;
+        while let Some(op @ ('+' | '-')) = self.peek_op() {
+            self.pos += 1;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.product()?));
+        }
+        Ok(lhs)
+    }
+
+    fn product(&mut self) -> Result<Expr, ExprError> {
+        let mut lhs = self.unary()?;
+        while let Some(op @ ('*' | '/')) = self.peek_op() {
+            self.pos += 1;
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ExprError> {
+        match self.peek_op() {
+            Some('-') => {
+                self.pos += 1;
+                Ok(Expr::Neg(Box::new(self.unary()?)))
+            }
+            Some('+') => {
+                self.pos += 1;
+                self.unary()
+            }
+            _ => self.power(),
+        }
+    }
+
+    fn power(&mut self) -> Result<Expr, ExprError> {
+        let base = self.atom()?;
+        if self.peek_op() == Some('^') {
+            self.pos += 1;
+            let exponent = self.unary()?;
+            return Ok(Expr::Binary('^', Box::new(base), Box::new(exponent)));
+        }
+        Ok(base)
+    }
+
+    fn atom(&mut self) -> Result<Expr, ExprError> {
+        let (token, at) = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or(ExprError::UnexpectedEnd)?;
+        self.pos += 1;
+
+        match token {
+            Token::Num(v) => Ok(Expr::Num(v)),
+            Token::Op('(') => {
+                let inner = self.sum()?;
+                self.expect(')')?;
+                Ok(inner)
+            }
+            Token::Name(name) if self.peek_op() == Some('(') => {
+                let (func, arity) =
+                    Func::lookup(&name).ok_or_else(|| ExprError::UnknownName(name.clone()))?;
+                self.pos += 1;
+                let mut args = vec![self.sum()?];
+                while self.peek_op() == Some(',') {
+                    self.pos += 1;
+                    args.push(self.sum()?);
+                }
+                self.expect(')')?;
+                if args.len() != arity {
+                    return Err(ExprError::Arity(name, arity));
+                }
+                Ok(Expr::Call(func, args))
+            }
+            Token::Name(name) => match name.as_str() {
+                "t" => Ok(Expr::T),
+                "PI" => Ok(Expr::Num(std::f64::consts::PI as Float)),
+                "TAU" => Ok(Expr::Num(std::f64::consts::TAU as Float)),
+                "E" => Ok(Expr::Num(std::f64::consts::E as Float)),
+                _ => self
+                    .params
+                    .iter()
+                    .rposition(|(n, _)| *n == name)
+                    .map(Expr::Param)
+                    .ok_or(ExprError::UnknownName(name)),
+            },
+            token => Err(ExprError::UnexpectedToken(token.to_string(), at)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod expr_tests {
+    use super::*;
+    use crate::GlVec;
+
+    #[test]
+    fn test_expr_circle() {
+        let mut circle =
+            ExprCurve::parse("r = 10\nx(t) = r*cos(t*TAU)\ny(t) = r*sin(t*TAU)").unwrap();
+        assert_eq!(circle.get("r"), Some(10.0));
+
+        let quarter = GlVec::from(circle.eval(0.25));
+        assert!(quarter.x.abs() < 1e-4 && (quarter.y - 10.0).abs() < 1e-4);
+
+        circle.set("r", 2.0).unwrap();
+        assert!((GlVec::from(circle.eval(0.5)).x + 2.0).abs() < 1e-4);
+        assert_eq!(
+            circle.set("q", 1.0),
+            Err(ExprError::UnknownName("q".into()))
+        );
+    }
+
+    #[test]
+    fn test_expr_precedence() {
+        let curve =
+            ExprCurve::parse("a = 2; b = a^3; x(t) = -2^2 + b/4*t; y(t) = mix(1, 3, t)").unwrap();
+        let p = curve.eval(0.5);
+        assert_eq!(p.x, -4.0 + 1.0);
+        assert_eq!(p.y, 2.0);
+    }
+
+    #[test]
+    fn test_expr_derived_parameters() {
+        let mut curve =
+            ExprCurve::parse("a = 2; b = a^3; a = a + 1; x(t) = b*t; y(t) = a").unwrap();
+        assert_eq!(curve.get("b"), Some(8.0));
+        assert_eq!(curve.get("a"), Some(3.0));
+
+        // the redefinition of `a` shadows the one `b` depends on
+        curve.set("a", 5.0).unwrap();
+        assert_eq!((curve.get("b"), curve.eval(1.0).y), (Some(8.0), 5.0));
+
+        let mut curve = ExprCurve::parse("a = 2; b = a^3; x(t) = b*t").unwrap();
+        curve.set("a", 3.0).unwrap();
+        assert_eq!(curve.get("b"), Some(27.0));
+        assert_eq!(curve.eval(1.0).x, 27.0);
+
+        assert_eq!(
+            ExprCurve::parse("k = t + 1; x(t) = k").unwrap_err(),
+            ExprError::VaryingParameter("k".into())
+        );
+    }
+
+    #[test]
+    fn test_expr_clamp_reversed_bounds() {
+        let curve = ExprCurve::parse("x(t) = clamp(t, 1, 0); y(t) = clamp(t, 0, 0/0)").unwrap();
+        let p = curve.eval(0.5);
+        assert_eq!(p.x, 0.0);
+        assert_eq!(p.y, 0.5);
+    }
+
+    #[test]
+    fn test_expr_errors() {
+        assert_eq!(
+            ExprCurve::parse("x(t) = r * t").unwrap_err(),
+            ExprError::UnknownName("r".into())
+        );
+        assert_eq!(
+            ExprCurve::parse("x(t) = pow(t)").unwrap_err(),
+            ExprError::Arity("pow".into(), 2)
+        );
+        assert_eq!(
+            ExprCurve::parse("x(t) = (t").unwrap_err(),
+            ExprError::UnexpectedEnd
+        );
+        assert_eq!(
+            ExprCurve::parse("x(t) = t $ 2").unwrap_err(),
+            ExprError::UnexpectedChar('$', 3)
+        );
+        assert!(matches!(
+            ExprCurve::parse("x(t) t"),
+            Err(ExprError::MissingAssignment(_))
+        ));
+    }
+}
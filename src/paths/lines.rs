@@ -1,4 +1,4 @@
-use crate::{magnitude, GlVec, Point, Vector, VectorValuedFn};
+use crate::{magnitude, Float, GlVec, Path, Point, Primitive, Vector, VectorValuedFn};
 
 /// flat line in space with start and end
 #[derive(Debug, Clone, Copy)]
@@ -31,8 +31,16 @@ impl VectorValuedFn for LineSegment {
         magnitude(d)
     }
 
-    fn sample_optimal(&self) -> Vec<Vector> {
-        vec![self.start.into(), self.end.into()]
+    fn samples_optimal(&self) -> Box<dyn Iterator<Item = Vector> + '_> {
+        Box::new([self.start.into(), self.end.into()].into_iter())
+    }
+
+    fn samples_adaptive(&self, _: crate::Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        self.samples_optimal()
+    }
+
+    fn primitive(&self) -> Option<Primitive> {
+        Some(Primitive::Line(*self))
     }
 }
 
@@ -67,15 +75,115 @@ impl VectorValuedFn for Line {
         }
     }
 
-    fn sample_optimal(&self) -> Vec<Vector> {
-        vec![self.origin.into(), self.end().into()]
+    fn samples_optimal(&self) -> Box<dyn Iterator<Item = Vector> + '_> {
+        Box::new([self.origin.into(), self.end().into()].into_iter())
+    }
+
+    fn samples_adaptive(&self, _: crate::Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        self.samples_optimal()
+    }
+}
+
+/// open chain of straight segments through the points
+///
+/// `t` is distributed by arc length
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Polyline(pub Vec<Point>);
+
+impl Polyline {
+    /// flattens any function within the tolerance
+    pub fn flatten<F: VectorValuedFn + ?Sized>(f: &F, tolerance: crate::Float) -> Self {
+        Self(
+            f.flattened_with_tolerance(tolerance)
+                .into_iter()
+                .map(|v| GlVec::from(v).into())
+                .collect(),
+        )
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.0
+    }
+
+    fn lengths(&self) -> Vec<crate::Float> {
+        self.0
+            .windows(2)
+            .map(|w| magnitude(GlVec::from(w[1]) - GlVec::from(w[0])))
+            .collect()
+    }
+}
+
+impl VectorValuedFn for Polyline {
+    fn eval(&self, t: crate::Float) -> crate::Vector {
+        match self.0.len() {
+            0 => return GlVec::default().into(),
+            1 => return self.0[0].into(),
+            _ => {}
+        }
+
+        let lengths = self.lengths();
+        let target = t * lengths.iter().sum::<crate::Float>();
+        let mut accumulated = 0.0;
+        for (i, &length) in lengths.iter().enumerate() {
+            if target < accumulated + length {
+                let local_t = (target - accumulated) / length;
+                let (a, b) = (GlVec::from(self.0[i]), GlVec::from(self.0[i + 1]));
+                return a.lerp(b, local_t).into();
+            }
+            accumulated += length;
+        }
+        self.0[self.0.len() - 1].into()
+    }
+
+    fn length(&self) -> crate::Float {
+        self.lengths().iter().sum()
+    }
+
+    fn samples_optimal(&self) -> Box<dyn Iterator<Item = Vector> + '_> {
+        Box::new(self.0.iter().map(|p| (*p).into()))
+    }
+
+    fn samples_adaptive(&self, _: crate::Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        self.samples_optimal()
+    }
+}
+
+/// shoelace area of the closed ring through the `xy` points,
+/// positive when counter-clockwise, the closing edge is implied
+pub(crate) fn ring_signed_area(ring: impl IntoIterator<Item = [Float; 2]>) -> Float {
+    let mut ring = ring.into_iter();
+    let Some(first) = ring.next() else {
+        return 0.0;
+    };
+    let (mut sum, mut prev) = (0.0, first);
+    for p in ring.chain(std::iter::once(first)) {
+        sum += prev[0] * p[1] - p[0] * prev[1];
+        prev = p;
+    }
+    sum / 2.0
+}
+
+impl From<Polyline> for Path {
+    fn from(value: Polyline) -> Self {
+        Path::new(vec![Box::new(value)])
+    }
+}
+
+impl From<&Path> for Polyline {
+    fn from(value: &Path) -> Self {
+        Self(
+            value
+                .samples_optimal()
+                .map(|v| GlVec::from(v).into())
+                .collect(),
+        )
     }
 }
 
 #[cfg(test)]
 mod line_tests {
     use super::*;
-    use crate::test_util::test_name;
+    use crate::test_util::{point, test_name};
     use insta::assert_debug_snapshot;
 
     #[test]
@@ -125,4 +233,22 @@ mod line_tests {
             [line.eval(0.0), line.eval(0.5), line.eval(1.0)]
         );
     }
+
+    #[test]
+    fn test_polyline() {
+        let polyline = Polyline(vec![point(0.0, 0.0), point(3.0, 0.0), point(3.0, 1.0)]);
+        assert_eq!(polyline.length(), 4.0);
+        assert_eq!(polyline.eval(0.5), Vector::from(point(2.0, 0.0)));
+        assert_eq!(polyline.end(), point(3.0, 1.0));
+        assert_eq!(polyline.sample_optimal().len(), 3);
+
+        let path = Path::from(polyline.clone());
+        assert_eq!(path.segments().len(), 1);
+        assert_eq!(Polyline::from(&path), polyline);
+
+        let path = Path::rectangle(point(0.0, 0.0), Vector::from(point(2.0, 1.0)));
+        let outline = Polyline::from(&path);
+        assert_eq!(outline.0.len(), 5);
+        assert_eq!(outline.length(), path.length());
+    }
 }
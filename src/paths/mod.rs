@@ -1,9 +1,17 @@
 mod arcs;
 mod curves;
+mod expr;
 mod lines;
+mod parametric;
 mod path;
+mod primitive;
+mod splines;
 
 pub use arcs::*;
 pub use curves::*;
+pub use expr::*;
 pub use lines::*;
+pub use parametric::*;
 pub use path::*;
+pub use primitive::*;
+pub use splines::*;
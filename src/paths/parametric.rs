@@ -0,0 +1,379 @@
+use crate::{sampled_length, Float, Point, Vector, VectorValuedFn};
+
+fn tau() -> Float {
+    crate::Angle::TAU.to_radians()
+}
+
+fn at(center: Point, x: Float, y: Float) -> Vector {
+    Vector {
+        x: center.x + x,
+        y: center.y + y,
+        #[cfg(feature = "3d")]
+        z: center.z,
+    }
+}
+
+/// rose curve `r = cos(k θ)` with `k = petals_n / petals_d`
+///
+/// `t` covers the full period of the curve
+#[derive(Debug, Clone, Copy)]
+pub struct Rose {
+    pub center: Point,
+    pub radius: Float,
+    pub petals_n: u32,
+    pub petals_d: u32,
+}
+
+impl Rose {
+    fn period(&self) -> Float {
+        let (n, d) = (self.petals_n.max(1), self.petals_d.max(1));
+        let d = d / gcd(n, d);
+        if (n / gcd(n, self.petals_d.max(1))) % 2 == 1 && d % 2 == 1 {
+            std::f64::consts::PI as Float * d as Float
+        } else {
+            tau() * d as Float
+        }
+    }
+}
+
+impl VectorValuedFn for Rose {
+    fn eval(&self, t: Float) -> Vector {
+        let theta = t * self.period();
+        let k = self.petals_n as Float / self.petals_d.max(1) as Float;
+        let r = self.radius * (k * theta).cos();
+        at(self.center, r * theta.cos(), r * theta.sin())
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+/// Lissajous figure `(a sin(fx θ + phase), b sin(fy θ))`
+#[derive(Debug, Clone, Copy)]
+pub struct Lissajous {
+    pub center: Point,
+    pub size: Vector,
+    pub frequency_x: Float,
+    pub frequency_y: Float,
+    pub phase: Float,
+}
+
+impl VectorValuedFn for Lissajous {
+    fn eval(&self, t: Float) -> Vector {
+        let theta = t * tau();
+        at(
+            self.center,
+            self.size.x * (self.frequency_x * theta + self.phase).sin(),
+            self.size.y * (self.frequency_y * theta).sin(),
+        )
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+/// curve traced by a point at `distance` from the center of a circle of `rolling_radius`
+/// rolling around a circle of `fixed_radius`
+///
+/// `outside` rolls on the outside making an epitrochoid,
+/// otherwise it's a hypotrochoid, the spirograph curve,
+/// `t` covers `turns` revolutions around the fixed circle
+#[derive(Debug, Clone, Copy)]
+pub struct Trochoid {
+    pub center: Point,
+    pub fixed_radius: Float,
+    pub rolling_radius: Float,
+    pub distance: Float,
+    pub outside: bool,
+    pub turns: Float,
+}
+
+impl Trochoid {
+    /// epicycloid, the point is on the rim of the rolling circle
+    pub fn epicycloid(center: Point, fixed_radius: Float, rolling_radius: Float) -> Self {
+        Self {
+            center,
+            fixed_radius,
+            rolling_radius,
+            distance: rolling_radius,
+            outside: true,
+            turns: closing_turns(fixed_radius, rolling_radius),
+        }
+    }
+
+    /// hypotrochoid closed after the least number of turns
+    pub fn hypotrochoid(
+        center: Point,
+        fixed_radius: Float,
+        rolling_radius: Float,
+        distance: Float,
+    ) -> Self {
+        Self {
+            center,
+            fixed_radius,
+            rolling_radius,
+            distance,
+            outside: false,
+            turns: closing_turns(fixed_radius, rolling_radius),
+        }
+    }
+}
+
+impl VectorValuedFn for Trochoid {
+    fn eval(&self, t: Float) -> Vector {
+        let theta = t * tau() * self.turns;
+        let (r0, r1, d) = (self.fixed_radius, self.rolling_radius, self.distance);
+
+        if self.outside {
+            let k = (r0 + r1) / r1;
+            at(
+                self.center,
+                (r0 + r1) * theta.cos() - d * (k * theta).cos(),
+                (r0 + r1) * theta.sin() - d * (k * theta).sin(),
+            )
+        } else {
+            let k = (r0 - r1) / r1;
+            at(
+                self.center,
+                (r0 - r1) * theta.cos() + d * (k * theta).cos(),
+                (r0 - r1) * theta.sin() - d * (k * theta).sin(),
+            )
+        }
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+/// number of turns after which a trochoid closes,
+/// approximated for radii that aren't integer ratios
+fn closing_turns(fixed_radius: Float, rolling_radius: Float) -> Float {
+    const SCALE: Float = 1000.0;
+    let a = (fixed_radius * SCALE).round() as u64;
+    let b = (rolling_radius * SCALE).round() as u64;
+    if a == 0 || b == 0 {
+        return 1.0;
+    }
+    (b / gcd64(a, b)) as Float
+}
+
+/// Archimedean spiral `r = start_radius + growth θ`
+#[derive(Debug, Clone, Copy)]
+pub struct ArchimedeanSpiral {
+    pub center: Point,
+    pub start_radius: Float,
+    /// radius gained per radian
+    pub growth: Float,
+    pub turns: Float,
+}
+
+impl VectorValuedFn for ArchimedeanSpiral {
+    fn eval(&self, t: Float) -> Vector {
+        let theta = t * tau() * self.turns;
+        let r = self.start_radius + self.growth * theta;
+        at(self.center, r * theta.cos(), r * theta.sin())
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+/// logarithmic spiral `r = start_radius e^(growth θ)`
+#[derive(Debug, Clone, Copy)]
+pub struct LogarithmicSpiral {
+    pub center: Point,
+    pub start_radius: Float,
+    pub growth: Float,
+    pub turns: Float,
+}
+
+impl VectorValuedFn for LogarithmicSpiral {
+    fn eval(&self, t: Float) -> Vector {
+        let theta = t * tau() * self.turns;
+        let r = self.start_radius * (self.growth * theta).exp();
+        at(self.center, r * theta.cos(), r * theta.sin())
+    }
+
+    fn length(&self) -> Float {
+        let theta = tau() * self.turns;
+        if self.growth == 0.0 {
+            return self.start_radius.abs() * theta;
+        }
+        let g = self.growth;
+        self.start_radius.abs() * (1.0 + g * g).sqrt() / g * ((g * theta).exp() - 1.0)
+    }
+}
+
+/// Gielis superformula
+///
+/// `r = (|cos(m θ / 4) / a|^n2 + |sin(m θ / 4) / b|^n3)^(-1 / n1)`
+#[derive(Debug, Clone, Copy)]
+pub struct Superformula {
+    pub center: Point,
+    pub radius: Float,
+    pub m: Float,
+    pub n1: Float,
+    pub n2: Float,
+    pub n3: Float,
+    pub a: Float,
+    pub b: Float,
+}
+
+impl Superformula {
+    pub fn radius_at(&self, theta: Float) -> Float {
+        let c = ((self.m * theta / 4.0).cos() / self.a).abs().powf(self.n2);
+        let s = ((self.m * theta / 4.0).sin() / self.b).abs().powf(self.n3);
+        let sum = c + s;
+        if sum == 0.0 {
+            0.0
+        } else {
+            sum.powf(-1.0 / self.n1)
+        }
+    }
+}
+
+impl VectorValuedFn for Superformula {
+    fn eval(&self, t: Float) -> Vector {
+        let theta = t * tau();
+        let r = self.radius * self.radius_at(theta);
+        at(self.center, r * theta.cos(), r * theta.sin())
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+fn gcd(a: u32, b: u32) -> u32 {
+    gcd64(a as u64, b as u64) as u32
+}
+
+fn gcd64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.max(1)
+}
+
+#[cfg(test)]
+mod parametric_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::GlVec;
+
+    fn close(a: Vector, b: Vector) -> bool {
+        (GlVec::from(a) - GlVec::from(b)).length() < 1e-3
+    }
+
+    #[test]
+    fn test_curves_are_closed() {
+        let rose = Rose {
+            center: point(0.0, 0.0),
+            radius: 10.0,
+            petals_n: 3,
+            petals_d: 1,
+        };
+        let even_rose = Rose {
+            petals_n: 2,
+            ..rose
+        };
+        let lissajous = Lissajous {
+            center: point(0.0, 0.0),
+            size: Vector::from(GlVec::splat(5.0)),
+            frequency_x: 3.0,
+            frequency_y: 2.0,
+            phase: 0.5,
+        };
+        let spirograph = Trochoid::hypotrochoid(point(0.0, 0.0), 5.0, 3.0, 5.0);
+        let epicycloid = Trochoid::epicycloid(point(0.0, 0.0), 3.0, 1.0);
+        let superformula = Superformula {
+            center: point(0.0, 0.0),
+            radius: 10.0,
+            m: 6.0,
+            n1: 1.0,
+            n2: 7.0,
+            n3: 8.0,
+            a: 1.0,
+            b: 1.0,
+        };
+
+        let curves: [&dyn VectorValuedFn; 6] = [
+            &rose,
+            &even_rose,
+            &lissajous,
+            &spirograph,
+            &epicycloid,
+            &superformula,
+        ];
+        for curve in curves {
+            assert!(close(curve.eval(0.0), curve.eval(1.0)));
+            assert!(!close(curve.eval(0.0), curve.eval(0.3)));
+        }
+    }
+
+    #[test]
+    fn test_rose_period() {
+        let rose = Rose {
+            center: point(0.0, 0.0),
+            radius: 1.0,
+            petals_n: 3,
+            petals_d: 1,
+        };
+        assert_eq!(rose.period(), std::f64::consts::PI as Float);
+        assert_eq!(
+            Rose {
+                petals_n: 4,
+                ..rose
+            }
+            .period(),
+            tau()
+        );
+        assert_eq!(
+            Rose {
+                petals_n: 1,
+                petals_d: 2,
+                ..rose
+            }
+            .period(),
+            tau() * 2.0
+        );
+    }
+
+    #[test]
+    fn test_trochoid() {
+        assert_eq!(
+            Trochoid::hypotrochoid(point(0.0, 0.0), 5.0, 3.0, 5.0).turns,
+            3.0
+        );
+        let cardioid = Trochoid::epicycloid(point(0.0, 0.0), 1.0, 1.0);
+        assert!(close(
+            cardioid.eval(0.0),
+            Vector::from(GlVec::from(point(0.0, 0.0)) + GlVec::X)
+        ));
+        assert!((cardioid.length() - 16.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_spirals() {
+        let spiral = ArchimedeanSpiral {
+            center: point(0.0, 0.0),
+            start_radius: 1.0,
+            growth: 1.0,
+            turns: 2.0,
+        };
+        let end = GlVec::from(spiral.eval(1.0));
+        assert!((end.length() - (1.0 + 2.0 * tau())).abs() < 1e-3);
+
+        let log = LogarithmicSpiral {
+            center: point(0.0, 0.0),
+            start_radius: 1.0,
+            growth: 0.1,
+            turns: 1.0,
+        };
+        assert!((log.length() - sampled_length(&log)).abs() < 0.01);
+    }
+}
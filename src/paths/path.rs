@@ -1,4 +1,10 @@
-use crate::{Angle, Float, Point, Vector, VectorValuedFn};
+use std::collections::HashMap;
+
+use crate::{
+    dedup_points, orient2d, ring_signed_area, Affine, Angle, BBox, Float, GlVec, Orientation,
+    OrientedBBox, Point, Polyline, QuadraticCurve, Reversed, Superformula, SweepArc, Transformed,
+    Vector, VectorValuedFn, WidthProfile,
+};
 
 use super::LineSegment;
 
@@ -9,13 +15,47 @@ pub type PathSegment = Box<dyn VectorValuedFn>;
 pub struct Path {
     segments: Vec<PathSegment>,
     lengths: Vec<Float>,
+    /// free form tags carried along for downstream renderers,
+    /// e.g. the epoch or segment that produced the path
+    pub meta: Option<HashMap<String, String>>,
+    /// stroke width along the path, e.g. pen pressure of an imported drawing
+    pub width: Option<WidthProfile>,
 }
 
 impl Path {
     pub fn new(segments: Vec<PathSegment>) -> Self {
         let lengths = segments.iter().map(|s| s.length()).collect();
 
-        Self { segments, lengths }
+        Self {
+            segments,
+            lengths,
+            meta: None,
+            width: None,
+        }
+    }
+
+    /// sets a metadata tag returning the path
+    pub fn with_meta(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.set_meta(key, value);
+        self
+    }
+
+    /// sets a metadata tag
+    pub fn set_meta(&mut self, key: impl Into<String>, value: impl Into<String>) {
+        self.meta
+            .get_or_insert_with(HashMap::new)
+            .insert(key.into(), value.into());
+    }
+
+    /// sets the stroke width profile returning the path
+    pub fn with_width(mut self, width: WidthProfile) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// reads a metadata tag
+    pub fn get_meta(&self, key: &str) -> Option<&str> {
+        self.meta.as_ref()?.get(key).map(String::as_str)
     }
 
     pub fn push(&mut self, segment: PathSegment) {
@@ -23,6 +63,210 @@ impl Path {
         self.segments.push(segment);
     }
 
+    /// segments of the path in drawing order
+    pub fn segments(&self) -> &[PathSegment] {
+        &self.segments
+    }
+
+    /// bounds of the flattened path, `None` for empty paths
+    pub fn bounds(&self) -> Option<BBox> {
+        BBox::from_points(self.sample_optimal())
+    }
+
+    /// area enclosed by the flattened path in the `xy` plane,
+    /// positive when it winds counter clockwise
+    ///
+    /// the path is treated as closed whether or not it ends where it started
+    pub fn signed_area(&self) -> Float {
+        ring_signed_area(self.samples_optimal().map(|v| [v.x, v.y]))
+    }
+
+    /// enclosed area regardless of the winding
+    pub fn area(&self) -> Float {
+        self.signed_area().abs()
+    }
+
+    /// direction the closed path winds in,
+    /// [`Orientation::Collinear`] when it encloses nothing
+    pub fn winding(&self) -> Orientation {
+        let area = self.signed_area();
+        if area > 0.0 {
+            Orientation::CounterClockwise
+        } else if area < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    /// center of mass of the enclosed area in the plane of the path start,
+    /// `None` when the path encloses nothing
+    pub fn centroid(&self) -> Option<Point> {
+        let area = self.signed_area();
+        if area == 0.0 {
+            return None;
+        }
+
+        let points = self.sample_optimal();
+        let (cx, cy) = points.iter().zip(points.iter().cycle().skip(1)).fold(
+            (0.0, 0.0),
+            |(cx, cy), (a, b)| {
+                let cross = a.x * b.y - b.x * a.y;
+                (cx + (a.x + b.x) * cross, cy + (a.y + b.y) * cross)
+            },
+        );
+
+        let mut centroid = self.start();
+        centroid.x = cx / (6.0 * area);
+        centroid.y = cy / (6.0 * area);
+        Some(centroid)
+    }
+
+    /// smallest convex polygon around the flattened path in the `xy` plane,
+    /// closed and counter clockwise, empty for empty paths
+    pub fn convex_hull(&self) -> Self {
+        let mut points: Vec<Point> = self.samples_optimal().map(Point::from).collect();
+        points.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+        points.dedup_by(|a, b| a.x == b.x && a.y == b.y);
+        if points.len() < 3 {
+            return match points.len() {
+                0 => Self::default(),
+                _ => Polyline(points).into(),
+            };
+        }
+
+        // monotone chain, lower half left to right then upper half back
+        let mut hull: Vec<Point> = Vec::with_capacity(points.len() + 1);
+        for pass in [
+            points.iter().collect::<Vec<_>>(),
+            points.iter().rev().collect(),
+        ] {
+            let floor = hull.len();
+            for p in pass {
+                while hull.len() >= floor + 2
+                    && orient2d(hull[hull.len() - 2], hull[hull.len() - 1], *p)
+                        != Orientation::CounterClockwise
+                {
+                    hull.pop();
+                }
+                hull.push(*p);
+            }
+            hull.pop();
+        }
+        hull.push(hull[0]);
+
+        Polyline(hull).into()
+    }
+
+    /// smallest area box around the flattened path in the `xy` plane,
+    /// one of its sides lies along an edge of the [`Path::convex_hull`]
+    ///
+    /// `None` for empty paths
+    pub fn oriented_bbox(&self) -> Option<OrientedBBox> {
+        let hull = self.convex_hull().sample_optimal();
+        let first = hull.first()?;
+
+        let mut best: Option<OrientedBBox> = None;
+        let directions = hull
+            .windows(2)
+            .map(|w| (w[1].x - w[0].x, w[1].y - w[0].y))
+            .chain([(1.0, 0.0)]);
+        for (dx, dy) in directions {
+            let length = dx.hypot(dy);
+            if length == 0.0 {
+                continue;
+            }
+            let (cos, sin) = (dx / length, dy / length);
+            let (mut u_min, mut u_max, mut v_min, mut v_max) =
+                (Float::MAX, Float::MIN, Float::MAX, Float::MIN);
+            for p in hull.iter() {
+                let (u, v) = (p.x * cos + p.y * sin, -p.x * sin + p.y * cos);
+                (u_min, u_max) = (u_min.min(u), u_max.max(u));
+                (v_min, v_max) = (v_min.min(v), v_max.max(v));
+            }
+
+            let (u, v) = ((u_min + u_max) / 2.0, (v_min + v_max) / 2.0);
+            let mut center: Point = (*first).into();
+            center.x = u * cos - v * sin;
+            center.y = u * sin + v * cos;
+            let candidate = OrientedBBox {
+                center,
+                width: u_max - u_min,
+                height: v_max - v_min,
+                angle: Angle::from_radians(sin.atan2(cos)),
+            };
+            if best.is_none_or(|b| candidate.area() < b.area()) {
+                best = Some(candidate);
+            }
+        }
+
+        best
+    }
+
+    /// flattened path with every point pulled `strength` of the way
+    /// toward the middle of its neighbours, repeated `iterations` times
+    ///
+    /// ends of open paths stay in place, closed paths stay closed,
+    /// the metadata and width are kept
+    pub fn smooth(&self, iterations: usize, strength: Float) -> Self {
+        let mut points: Vec<GlVec> = self.samples_optimal().map(GlVec::from).collect();
+        let closed = points.len() > 2 && points.first() == points.last();
+        if closed {
+            points.pop();
+        }
+
+        let strength = strength.clamp(0.0, 1.0);
+        let n = points.len();
+        for _ in 0..iterations {
+            if n < 3 {
+                break;
+            }
+            points = (0..n)
+                .map(|i| {
+                    let (prev, next) = match (closed, i) {
+                        (false, 0) => return points[0],
+                        (false, i) if i == n - 1 => return points[i],
+                        _ => (points[(i + n - 1) % n], points[(i + 1) % n]),
+                    };
+                    points[i] + ((prev + next) / 2.0 - points[i]) * strength
+                })
+                .collect();
+        }
+
+        if closed {
+            points.push(points[0]);
+        }
+        let mut path: Self = Polyline(points.into_iter().map(Point::from).collect()).into();
+        path.meta = self.meta.clone();
+        path.width = self.width.clone();
+        path
+    }
+
+    /// applies the affine transform to every segment keeping the metadata and width
+    pub fn transformed(self, affine: Affine) -> Self {
+        let (meta, width) = (self.meta, self.width);
+        let mut path = Self::new(
+            self.segments
+                .into_iter()
+                .map(|source| Box::new(Transformed { affine, source }) as PathSegment)
+                .collect(),
+        );
+        path.meta = meta;
+        path.width = width;
+        path
+    }
+
+    /// flips the direction of the path in place
+    pub fn reverse(&mut self) {
+        self.segments = std::mem::take(&mut self.segments)
+            .into_iter()
+            .rev()
+            .map(|source| Box::new(Reversed { source }) as PathSegment)
+            .collect();
+        self.lengths.reverse();
+        self.width = self.width.take().map(|w| w.reversed());
+    }
+
     /// draws a poligon
     pub fn polygon(center: Point, size: Vector, n_sides: usize, start_angle: Angle) -> Self {
         let mut segments = Vec::new();
@@ -115,6 +359,205 @@ impl Path {
 
         Self::new(segments)
     }
+
+    /// full circle counter-clockwise from the `x` axis
+    pub fn circle(center: Point, radius: Float) -> Self {
+        Self::new(vec![Box::new(SweepArc::ellipse(
+            center,
+            circle_radius(radius),
+        ))])
+    }
+
+    /// ring between two circles as one closed path,
+    /// the outer circle counter-clockwise then the inner one back
+    /// joined by a bridge along the `x` axis, so fills leave the middle open
+    pub fn annulus(center: Point, r_inner: Float, r_outer: Float) -> Self {
+        Self::ring_sector(center, r_inner, r_outer, Angle::ZERO, Angle::TAU)
+    }
+
+    /// closed band between radii `r0` and `r1` from `start` over `sweep`,
+    /// the outer arc runs counter-clockwise and the inner one back
+    pub fn ring_sector(center: Point, r0: Float, r1: Float, start: Angle, sweep: Angle) -> Self {
+        let (r0, r1) = (r0.min(r1), r0.max(r1));
+        let arc = |radius: Float| SweepArc {
+            radius: circle_radius(radius),
+            center,
+            start_angle: start,
+            sweep_angle: sweep,
+        };
+        let (outer, inner) = (arc(r1), arc(r0));
+
+        Self::new(vec![
+            Box::new(outer),
+            Box::new(LineSegment {
+                start: outer.end(),
+                end: inner.end(),
+            }),
+            Box::new(Reversed { source: inner }),
+            Box::new(LineSegment {
+                start: inner.start(),
+                end: outer.start(),
+            }),
+        ])
+    }
+
+    /// superellipse `|x|^n + |y|^n = radius^n`,
+    /// `exponent` 2 is a circle and larger ones approach a square
+    pub fn squircle(center: Point, radius: Float, exponent: Float) -> Self {
+        Self::new(vec![Box::new(Superformula {
+            center,
+            radius,
+            m: 4.0,
+            n1: exponent,
+            n2: exponent,
+            n3: exponent,
+            a: 1.0,
+            b: 1.0,
+        })])
+    }
+
+    /// gear outline with `teeth` trapezoidal teeth,
+    /// roots on radius `r0` and tips on `r1`, each taking a quarter of the pitch
+    pub fn gear(center: Point, teeth: usize, r0: Float, r1: Float) -> Self {
+        let teeth = teeth.max(1);
+        let quarter = Angle::TAU / (teeth * 4) as Float;
+        let arc = |radius: Float, start_angle: Angle| SweepArc {
+            radius: circle_radius(radius),
+            center,
+            start_angle,
+            sweep_angle: quarter,
+        };
+
+        let mut segments: Vec<PathSegment> = Vec::with_capacity(teeth * 4);
+        for i in 0..teeth {
+            let start = quarter * (i * 4) as Float;
+            let (root, tip) = (arc(r0, start), arc(r1, start + quarter * 2.0));
+            segments.push(Box::new(root));
+            segments.push(Box::new(LineSegment {
+                start: root.end(),
+                end: tip.start(),
+            }));
+            segments.push(Box::new(tip));
+            segments.push(Box::new(LineSegment {
+                start: tip.end(),
+                end: polar(center, r0, start + quarter * 4.0),
+            }));
+        }
+
+        Self::new(segments)
+    }
+
+    /// circle of radius `r` bulging out by `depth` in `lobes` arches,
+    /// negative `depth` bites the arches inwards instead
+    pub fn scalloped_circle(center: Point, r: Float, lobes: usize, depth: Float) -> Self {
+        let lobes = lobes.max(2);
+        let step = Angle::TAU / lobes as Float;
+        // the quadratic passes halfway between the chord and its control point
+        let control = 2.0 * (r + depth) - r * (step / 2.0).cos();
+
+        Self::new(
+            (0..lobes)
+                .map(|i| {
+                    let start = step * i as Float;
+                    Box::new(QuadraticCurve {
+                        start: polar(center, r, start),
+                        control: polar(center, control, start + step / 2.0),
+                        end: polar(center, r, start + step),
+                    }) as PathSegment
+                })
+                .collect(),
+        )
+    }
+
+    /// flower outline of `petals` rounded petals
+    /// growing from radius `r0` to tips on `r1`
+    pub fn petal_ring(center: Point, petals: usize, r0: Float, r1: Float) -> Self {
+        let petals = petals.max(2);
+        let step = Angle::TAU / petals as Float;
+
+        let mut segments: Vec<PathSegment> = Vec::with_capacity(petals * 2);
+        for i in 0..petals {
+            let start = step * i as Float;
+            let tip = polar(center, r1, start + step / 2.0);
+            segments.push(Box::new(QuadraticCurve {
+                start: polar(center, r0, start),
+                control: polar(center, r1, start),
+                end: tip,
+            }));
+            segments.push(Box::new(QuadraticCurve {
+                start: tip,
+                control: polar(center, r1, start + step),
+                end: polar(center, r0, start + step),
+            }));
+        }
+
+        Self::new(segments)
+    }
+
+    /// star polygon with `points` tips on `outer_r` from the `x` axis
+    /// and valleys on `inner_r` midway between them turned by `twist`
+    pub fn star(
+        center: Point,
+        points: usize,
+        inner_r: Float,
+        outer_r: Float,
+        twist: Angle,
+    ) -> Self {
+        let points = points.max(2);
+        let step = Angle::TAU / points as Float;
+        let vertices: Vec<Point> = (0..points)
+            .flat_map(|i| {
+                let tip = step * i as Float;
+                [
+                    polar(center, outer_r, tip),
+                    polar(center, inner_r, tip + step / 2.0 + twist),
+                ]
+            })
+            .collect();
+
+        Self::new(
+            vertices
+                .iter()
+                .zip(vertices.iter().cycle().skip(1))
+                .map(|(start, end)| {
+                    Box::new(LineSegment {
+                        start: *start,
+                        end: *end,
+                    }) as PathSegment
+                })
+                .collect(),
+        )
+    }
+
+    /// overlapping circles construction, as in the seed of life:
+    /// the central circle of radius `r` followed by `circles` more of the same radius
+    /// centered on its rim, each passing through `center`
+    pub fn rosette(center: Point, circles: usize, r: Float) -> Vec<Self> {
+        let step = Angle::TAU / circles.max(1) as Float;
+        std::iter::once(Self::circle(center, r))
+            .chain((0..circles).map(|i| Self::circle(polar(center, r, step * i as Float), r)))
+            .collect()
+    }
+}
+
+/// point at `radius` from `center` in the `angle` direction
+fn polar(center: Point, radius: Float, angle: Angle) -> Point {
+    Point {
+        x: center.x + radius * angle.cos(),
+        y: center.y + radius * angle.sin(),
+        #[cfg(feature = "3d")]
+        z: center.z,
+    }
+}
+
+/// radius of a circle lying in the `xy` plane
+fn circle_radius(radius: Float) -> Vector {
+    Vector {
+        x: radius,
+        y: radius,
+        #[cfg(feature = "3d")]
+        z: 0.0,
+    }
 }
 
 impl VectorValuedFn for Path {
@@ -135,22 +578,25 @@ impl VectorValuedFn for Path {
         self.lengths.iter().sum()
     }
 
-    fn sample_optimal(&self) -> Vec<Vector> {
-        let mut all: Vec<Vector> = self
-            .segments
-            .iter()
-            .flat_map(|s| s.sample_optimal())
-            .collect();
-
-        all.dedup();
+    fn samples_optimal(&self) -> Box<dyn Iterator<Item = Vector> + '_> {
+        Box::new(dedup_points(
+            self.segments.iter().flat_map(|s| s.samples_optimal()),
+        ))
+    }
 
-        all
+    fn samples_adaptive(&self, tolerance: Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        Box::new(dedup_points(
+            self.segments
+                .iter()
+                .flat_map(move |s| s.samples_adaptive(tolerance)),
+        ))
     }
 }
 
 #[cfg(test)]
 mod path_tests {
     use super::*;
+    use crate::test_util::{point, vector};
     use crate::{test_util::test_name, LineSegment, Point};
     use insta::assert_debug_snapshot;
 
@@ -307,4 +753,266 @@ mod path_tests {
         let samples = rectangle.sample_optimal();
         assert_debug_snapshot!(test_name("rectangle"), samples);
     }
+
+    #[test]
+    fn test_path_reverse() {
+        let origin = point(0.0, 0.0);
+        let size = vector(1.0, 2.0);
+        let rectangle = Path::rectangle(origin, size);
+        let mut reversed = Path::rectangle(origin, size);
+        reversed.reverse();
+
+        let mut samples = rectangle.sample_optimal();
+        samples.reverse();
+
+        assert_eq!(reversed.sample_optimal(), samples);
+        assert_eq!(reversed.eval(0.25), rectangle.eval(0.75));
+    }
+
+    #[test]
+    fn test_path_meta() {
+        let origin = point(0.0, 0.0);
+        let size = vector(1.0, 1.0);
+        let mut path = Path::rectangle(origin, size).with_meta("epoch", "2");
+        assert_eq!(path.get_meta("epoch"), Some("2"));
+        assert_eq!(path.get_meta("segment"), None);
+
+        path.reverse();
+        path.set_meta("segment", "7");
+        assert_eq!(path.get_meta("epoch"), Some("2"));
+        assert_eq!(path.get_meta("segment"), Some("7"));
+        assert_eq!(Path::default().get_meta("epoch"), None);
+    }
+
+    #[test]
+    fn test_area_and_centroid() {
+        let origin = point(1.0, 2.0);
+        let size = vector(4.0, 2.0);
+        let mut rectangle = Path::rectangle(origin, size);
+
+        assert_eq!(rectangle.signed_area(), 8.0);
+        assert_eq!(rectangle.winding(), Orientation::CounterClockwise);
+        let centroid = rectangle.centroid().unwrap();
+        assert_eq!((centroid.x, centroid.y), (3.0, 3.0));
+
+        rectangle.reverse();
+        assert_eq!(rectangle.signed_area(), -8.0);
+        assert_eq!(rectangle.area(), 8.0);
+        assert_eq!(rectangle.winding(), Orientation::Clockwise);
+        assert_eq!(rectangle.centroid(), Some(centroid));
+
+        let line = Path::new(vec![Box::new(LineSegment {
+            start: origin,
+            end: Point::from(size),
+        })]);
+        assert_eq!(line.winding(), Orientation::Collinear);
+        assert_eq!(line.centroid(), None);
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        let zigzag: Path = Polyline(vec![
+            point(0.0, 0.0),
+            point(2.0, 1.0),
+            point(4.0, 0.0),
+            point(3.0, 2.0),
+            point(4.0, 4.0),
+            point(2.0, 3.0),
+            point(0.0, 4.0),
+            point(1.0, 2.0),
+        ])
+        .into();
+
+        let hull = zigzag.convex_hull();
+        assert_eq!(
+            hull.sample_optimal(),
+            [
+                point(0.0, 0.0),
+                point(4.0, 0.0),
+                point(4.0, 4.0),
+                point(0.0, 4.0),
+                point(0.0, 0.0)
+            ]
+            .map(Vector::from)
+        );
+        assert_eq!(hull.winding(), Orientation::CounterClockwise);
+        assert_eq!(Path::default().convex_hull().segments().len(), 0);
+    }
+
+    #[test]
+    fn test_oriented_bbox() {
+        let origin = point(1.0, 1.0);
+        let size = vector(4.0, 2.0);
+        let turned = Path::rectangle(origin, size)
+            .transformed(crate::affine_rotation(Angle::from_degrees(30.0)));
+
+        let obb = turned.oriented_bbox().unwrap();
+        let aligned = turned.bounds().unwrap();
+        assert!(obb.area() < aligned.width() * aligned.height());
+        assert!((obb.area() - 8.0).abs() < 1e-3, "{}", obb.area());
+        let centroid = turned.centroid().unwrap();
+        assert!((obb.center.x - centroid.x).abs() < 1e-3);
+        assert!((obb.center.y - centroid.y).abs() < 1e-3);
+
+        assert_eq!(Path::default().oriented_bbox(), None);
+    }
+
+    #[test]
+    fn test_smooth() {
+        let jagged = Path::from(Polyline(
+            (0..=10)
+                .map(|i| point(i as Float, (i % 2) as Float))
+                .collect(),
+        ))
+        .with_meta("layer", "trace");
+        let wobble = |path: &Path| {
+            path.sample_optimal()
+                .windows(2)
+                .map(|w| (w[1].y - w[0].y).abs())
+                .sum::<Float>()
+        };
+
+        let smooth = jagged.smooth(3, 0.5);
+        assert!(wobble(&smooth) < wobble(&jagged) / 4.0);
+        assert_eq!(smooth.start(), jagged.start());
+        assert_eq!(smooth.end(), jagged.end());
+        assert_eq!(smooth.get_meta("layer"), Some("trace"));
+        assert_eq!(
+            jagged.smooth(0, 0.5).sample_optimal(),
+            jagged.sample_optimal()
+        );
+
+        let square = Path::rectangle(point(0.0, 0.0), Vector::from(point(2.0, 2.0))).smooth(2, 1.0);
+        assert_eq!(square.start(), square.end());
+        assert!(square.area() < 4.0);
+    }
+
+    #[test]
+    fn test_circular_shapes() {
+        let center = point(1.0, 2.0);
+        let area = |r: Float| Angle::PI.to_radians() * r * r;
+
+        let circle = Path::circle(center, 5.0);
+        assert!((circle.area() - area(5.0)).abs() / area(5.0) < 0.01);
+        assert_eq!(circle.start(), circle.end());
+
+        let annulus = Path::annulus(center, 3.0, 5.0);
+        assert_eq!(annulus.start(), annulus.end());
+        let ring = area(5.0) - area(3.0);
+        assert!((annulus.area() - ring).abs() / ring < 0.01);
+        assert_eq!(Path::annulus(center, 5.0, 3.0).area(), annulus.area());
+
+        let quarter = Path::ring_sector(center, 3.0, 5.0, Angle::ZERO, Angle::FRAC_PI_2);
+        assert!((quarter.area() - ring / 4.0).abs() / ring < 0.01);
+        assert_eq!(quarter.winding(), Orientation::CounterClockwise);
+        let start = quarter.start();
+        assert!((start.x - 6.0).abs() < 1e-4 && (start.y - 2.0).abs() < 1e-4);
+        assert!(GlVec::from(quarter.start()).distance(quarter.end().into()) < 1e-4);
+
+        let round = Path::squircle(center, 5.0, 2.0);
+        assert!((round.area() - area(5.0)).abs() / area(5.0) < 0.01);
+        let square = Path::squircle(center, 5.0, 20.0);
+        assert!(square.area() > round.area() && square.area() < 100.0);
+    }
+
+    #[test]
+    fn test_ring_ornaments() {
+        let center = point(1.0, 2.0);
+        let radius = |v: Vector| (v.x - center.x).hypot(v.y - center.y);
+        let closed = |path: &Path| {
+            assert!(GlVec::from(path.start()).distance(path.end().into()) < 1e-3);
+            for pair in path.segments().windows(2) {
+                assert!(GlVec::from(pair[0].end()).distance(pair[1].start().into()) < 1e-3);
+            }
+        };
+
+        let gear = Path::gear(center, 12, 4.0, 5.0);
+        closed(&gear);
+        assert_eq!(gear.segments().len(), 48);
+        let radii: Vec<Float> = gear.samples_optimal().map(radius).collect();
+        assert!(radii.iter().all(|r| *r > 3.99 && *r < 5.01));
+        assert!(radii.iter().any(|r| *r > 4.99) && radii.iter().any(|r| *r < 4.01));
+        let pi = Angle::PI.to_radians();
+        assert!(gear.area() > pi * 16.0 && gear.area() < pi * 25.0);
+
+        let scallop = Path::scalloped_circle(center, 5.0, 8, 1.0);
+        closed(&scallop);
+        let peak = scallop.segments()[0].eval(0.5);
+        assert!((radius(peak) - 6.0).abs() < 1e-4);
+        let bitten = Path::scalloped_circle(center, 5.0, 8, -1.0);
+        assert!((radius(bitten.segments()[0].eval(0.5)) - 4.0).abs() < 1e-4);
+        assert!(bitten.area() < scallop.area());
+
+        let flower = Path::petal_ring(center, 6, 2.0, 5.0);
+        closed(&flower);
+        assert_eq!(flower.segments().len(), 12);
+        assert_eq!(flower.winding(), Orientation::CounterClockwise);
+        assert!(flower
+            .samples_optimal()
+            .map(radius)
+            .all(|r| r > 1.99 && r < 5.01));
+    }
+
+    #[test]
+    fn test_star_and_rosette() {
+        let center = point(1.0, 2.0);
+        let radius = |v: Vector| (v.x - center.x).hypot(v.y - center.y);
+
+        let star = Path::star(center, 5, 2.0, 5.0, Angle::ZERO);
+        assert_eq!(star.segments().len(), 10);
+        assert_eq!(star.start(), star.end());
+        assert!((radius(star.start().into()) - 5.0).abs() < 1e-4);
+        assert!((radius(star.segments()[0].end().into()) - 2.0).abs() < 1e-4);
+        assert_eq!(star.winding(), Orientation::CounterClockwise);
+
+        let twisted = Path::star(center, 5, 2.0, 5.0, Angle::from_degrees(10.0));
+        assert!(twisted.area() < star.area());
+
+        let rosette = Path::rosette(center, 6, 3.0);
+        assert_eq!(rosette.len(), 7);
+        for circle in &rosette[1..] {
+            let bounds = circle.bounds().unwrap();
+            let middle = Vector::from(bounds.center());
+            assert!((radius(middle) - 3.0).abs() < 1e-3);
+            assert!(circle.samples_optimal().any(|v| radius(v) < 0.1));
+        }
+    }
+
+    #[test]
+    fn test_flattened_with_scale() {
+        let center = point(0.0, 0.0);
+        let radius = vector(10.0, 10.0);
+        let circle = Path::new(vec![Box::new(crate::SweepArc::ellipse(center, radius))]);
+        let square = Path::rectangle(center, radius);
+
+        let zoomed_out = circle.flattened_with_scale(0.5).len();
+        let zoomed_in = circle.flattened_with_scale(10.0).len();
+
+        assert!(zoomed_out < 20, "{zoomed_out}");
+        assert!(zoomed_in > 2 * zoomed_out, "{zoomed_in}");
+        assert_eq!(square.flattened_with_scale(0.5).len(), 5);
+        assert_eq!(square.flattened_with_scale(10.0).len(), 5);
+    }
+
+    #[test]
+    fn test_flattened_with_tolerance() {
+        let center = point(0.0, 0.0);
+        let radius = vector(10.0, 10.0);
+        let circle = Path::new(vec![Box::new(crate::SweepArc::ellipse(center, radius))]);
+
+        for tolerance in [0.5, 0.1, 0.01] {
+            let points = circle.flattened_with_tolerance(tolerance);
+            for w in points.windows(2) {
+                let mid_x = (w[0].x + w[1].x) / 2.0;
+                let mid_y = (w[0].y + w[1].y) / 2.0;
+                let sagitta = 10.0 - mid_x.hypot(mid_y);
+                assert!(sagitta <= tolerance * 1.1, "{sagitta} > {tolerance}");
+            }
+        }
+
+        assert!(
+            circle.flattened_with_tolerance(0.01).len()
+                > circle.flattened_with_tolerance(0.5).len()
+        );
+    }
 }
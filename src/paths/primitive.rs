@@ -0,0 +1,65 @@
+use crate::{transform_point, Affine, CubicCurve, LineSegment, Point, QuadraticCurve, SweepArc};
+
+/// exact geometry of a segment
+///
+/// exporters use it to write native entities
+/// instead of flattening the segment into lines
+#[derive(Debug, Clone, Copy)]
+pub enum Primitive {
+    Line(LineSegment),
+    Arc(SweepArc),
+    QuadraticCurve(QuadraticCurve),
+    CubicCurve(CubicCurve),
+}
+
+impl Primitive {
+    /// same primitive traversed in the opposite direction
+    ///
+    /// arcs can't be reversed as sweep angles are always positive
+    pub fn reversed(self) -> Option<Self> {
+        match self {
+            Primitive::Line(l) => Some(Primitive::Line(LineSegment {
+                start: l.end,
+                end: l.start,
+            })),
+            Primitive::Arc(_) => None,
+            Primitive::QuadraticCurve(c) => Some(Primitive::QuadraticCurve(QuadraticCurve {
+                start: c.end,
+                control: c.control,
+                end: c.start,
+            })),
+            Primitive::CubicCurve(c) => Some(Primitive::CubicCurve(CubicCurve {
+                start: c.end,
+                control1: c.control2,
+                control2: c.control1,
+                end: c.start,
+            })),
+        }
+    }
+
+    /// primitive with all points transformed by `affine`
+    ///
+    /// arcs aren't affine invariant and can't be transformed
+    pub fn transformed(self, affine: &Affine) -> Option<Self> {
+        let t = |p: Point| -> Point { transform_point(affine, p.into()).into() };
+
+        match self {
+            Primitive::Line(l) => Some(Primitive::Line(LineSegment {
+                start: t(l.start),
+                end: t(l.end),
+            })),
+            Primitive::Arc(_) => None,
+            Primitive::QuadraticCurve(c) => Some(Primitive::QuadraticCurve(QuadraticCurve {
+                start: t(c.start),
+                control: t(c.control),
+                end: t(c.end),
+            })),
+            Primitive::CubicCurve(c) => Some(Primitive::CubicCurve(CubicCurve {
+                start: t(c.start),
+                control1: t(c.control1),
+                control2: t(c.control2),
+                end: t(c.end),
+            })),
+        }
+    }
+}
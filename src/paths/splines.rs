@@ -0,0 +1,362 @@
+use crate::{sampled_length, CubicCurve, Float, GlVec, Path, Point, Vector, VectorValuedFn};
+
+/// non-uniform rational B-spline of any degree
+///
+/// `knots` must hold `control_points.len() + degree + 1` non-decreasing values,
+/// `t` maps onto the valid knot range `knots[degree]..knots[control_points.len()]`
+#[derive(Debug, Clone)]
+pub struct Nurbs {
+    pub degree: usize,
+    pub control_points: Vec<Point>,
+    pub weights: Vec<Float>,
+    pub knots: Vec<Float>,
+}
+
+/// non-rational B-spline, a [`Nurbs`] with all weights equal to one
+#[derive(Debug, Clone)]
+pub struct BSpline {
+    pub degree: usize,
+    pub control_points: Vec<Point>,
+    pub knots: Vec<Float>,
+}
+
+impl Nurbs {
+    pub fn new(
+        degree: usize,
+        control_points: Vec<Point>,
+        weights: Vec<Float>,
+        knots: Vec<Float>,
+    ) -> Self {
+        assert!(degree >= 1, "degree must be at least one");
+        assert!(
+            control_points.len() > degree,
+            "not enough control points for degree"
+        );
+        assert_eq!(
+            weights.len(),
+            control_points.len(),
+            "one weight per control point"
+        );
+        assert_eq!(
+            knots.len(),
+            control_points.len() + degree + 1,
+            "invalid knot vector length"
+        );
+        Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        }
+    }
+
+    /// nurbs passing through the first and last control points with evenly spaced inner knots
+    pub fn clamped(degree: usize, control_points: Vec<Point>, weights: Vec<Float>) -> Self {
+        let knots = clamped_knots(degree, control_points.len());
+        Self::new(degree, control_points, weights, knots)
+    }
+
+    fn homogeneous(&self) -> Vec<(GlVec, Float)> {
+        self.control_points
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(p, w)| (GlVec::from(*p) * *w, *w))
+            .collect()
+    }
+
+    fn domain(&self) -> (Float, Float) {
+        (
+            self.knots[self.degree],
+            self.knots[self.control_points.len()],
+        )
+    }
+
+    fn knot_at(&self, t: Float) -> Float {
+        let (a, b) = self.domain();
+        a + (b - a) * t
+    }
+
+    /// point and first derivative with respect to the knot parameter
+    fn eval_with_derivative(&self, u: Float) -> (GlVec, GlVec) {
+        let points = self.homogeneous();
+        let (a, w) = de_boor(self.degree, &points, &self.knots, u);
+        let (da, dw) = if self.degree > 0 {
+            let (d_points, d_knots) = derivative_points(self.degree, &points, &self.knots);
+            de_boor(self.degree - 1, &d_points, &d_knots, u)
+        } else {
+            (GlVec::ZERO, 0.0)
+        };
+        let p = a / w;
+        (p, (da - p * dw) / w)
+    }
+
+    /// distinct knot values bounding the polynomial pieces within the domain
+    fn spans(&self) -> Vec<(Float, Float)> {
+        let (start, end) = self.domain();
+        let mut breaks: Vec<Float> = self.knots[self.degree..=self.control_points.len()]
+            .iter()
+            .copied()
+            .filter(|k| *k >= start && *k <= end)
+            .collect();
+        breaks.dedup();
+        breaks.windows(2).map(|w| (w[0], w[1])).collect()
+    }
+
+    /// cubic Bézier chain matching position and tangent at every knot
+    ///
+    /// exact for non-rational splines up to degree 3, an approximation otherwise
+    pub fn to_cubic_curves(&self) -> Vec<CubicCurve> {
+        self.spans()
+            .into_iter()
+            .map(|(a, b)| {
+                let (p0, d0) = self.eval_with_derivative(a);
+                let (p3, d3) = self.eval_with_derivative(b);
+                let h = (b - a) / 3.0;
+                CubicCurve {
+                    start: p0.into(),
+                    control1: (p0 + d0 * h).into(),
+                    control2: (p3 - d3 * h).into(),
+                    end: p3.into(),
+                }
+            })
+            .collect()
+    }
+
+    /// path of cubic Bézier segments for export
+    pub fn to_path(&self) -> Path {
+        Path::new(
+            self.to_cubic_curves()
+                .into_iter()
+                .map(|c| Box::new(c) as _)
+                .collect(),
+        )
+    }
+}
+
+impl VectorValuedFn for Nurbs {
+    fn eval(&self, t: Float) -> Vector {
+        let (a, w) = de_boor(
+            self.degree,
+            &self.homogeneous(),
+            &self.knots,
+            self.knot_at(t),
+        );
+        (a / w).into()
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+
+    fn derivative(&self, t: Float) -> Vector {
+        let (a, b) = self.domain();
+        let (_, d) = self.eval_with_derivative(self.knot_at(t));
+        (d * (b - a)).into()
+    }
+}
+
+impl BSpline {
+    pub fn new(degree: usize, control_points: Vec<Point>, knots: Vec<Float>) -> Self {
+        let weights = vec![1.0; control_points.len()];
+        let nurbs = Nurbs::new(degree, control_points, weights, knots);
+        Self {
+            degree: nurbs.degree,
+            control_points: nurbs.control_points,
+            knots: nurbs.knots,
+        }
+    }
+
+    /// B-spline passing through the first and last control points with evenly spaced inner knots
+    pub fn clamped(degree: usize, control_points: Vec<Point>) -> Self {
+        let knots = clamped_knots(degree, control_points.len());
+        Self::new(degree, control_points, knots)
+    }
+
+    /// exact cubic Bézier chain for degrees up to 3
+    pub fn to_cubic_curves(&self) -> Vec<CubicCurve> {
+        self.to_nurbs().to_cubic_curves()
+    }
+
+    pub fn to_path(&self) -> Path {
+        self.to_nurbs().to_path()
+    }
+
+    pub fn to_nurbs(&self) -> Nurbs {
+        Nurbs {
+            degree: self.degree,
+            control_points: self.control_points.clone(),
+            weights: vec![1.0; self.control_points.len()],
+            knots: self.knots.clone(),
+        }
+    }
+}
+
+impl From<BSpline> for Nurbs {
+    fn from(value: BSpline) -> Self {
+        value.to_nurbs()
+    }
+}
+
+impl VectorValuedFn for BSpline {
+    fn eval(&self, t: Float) -> Vector {
+        self.to_nurbs().eval(t)
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+
+    fn derivative(&self, t: Float) -> Vector {
+        self.to_nurbs().derivative(t)
+    }
+}
+
+fn clamped_knots(degree: usize, n_points: usize) -> Vec<Float> {
+    let inner = n_points.saturating_sub(degree + 1);
+    let mut knots = vec![0.0; degree + 1];
+    knots.extend((1..=inner).map(|i| i as Float / (inner + 1) as Float));
+    knots.extend(std::iter::repeat_n(1.0, degree + 1));
+    knots
+}
+
+fn de_boor(degree: usize, points: &[(GlVec, Float)], knots: &[Float], u: Float) -> (GlVec, Float) {
+    let n = points.len();
+    let mut k = degree;
+    while k + 1 < n && u >= knots[k + 1] {
+        k += 1;
+    }
+
+    let mut d: Vec<(GlVec, Float)> = points[k - degree..=k].to_vec();
+    for r in 1..=degree {
+        for j in (r..=degree).rev() {
+            let left = knots[j + k - degree];
+            let right = knots[j + 1 + k - r];
+            let alpha = if right > left {
+                (u - left) / (right - left)
+            } else {
+                0.0
+            };
+            d[j] = (
+                d[j - 1].0 * (1.0 - alpha) + d[j].0 * alpha,
+                d[j - 1].1 * (1.0 - alpha) + d[j].1 * alpha,
+            );
+        }
+    }
+    d[degree]
+}
+
+fn derivative_points(
+    degree: usize,
+    points: &[(GlVec, Float)],
+    knots: &[Float],
+) -> (Vec<(GlVec, Float)>, Vec<Float>) {
+    let d_points = points
+        .windows(2)
+        .enumerate()
+        .map(|(i, w)| {
+            let span = knots[i + degree + 1] - knots[i + 1];
+            if span > 0.0 {
+                let s = degree as Float / span;
+                ((w[1].0 - w[0].0) * s, (w[1].1 - w[0].1) * s)
+            } else {
+                (GlVec::ZERO, 0.0)
+            }
+        })
+        .collect();
+    (d_points, knots[1..knots.len() - 1].to_vec())
+}
+
+#[cfg(test)]
+mod splines_tests {
+    use super::*;
+    use crate::test_util::point;
+
+    fn distance(a: Vector, b: Vector) -> Float {
+        (GlVec::from(a) - GlVec::from(b)).length()
+    }
+
+    #[test]
+    fn test_bspline_clamped_endpoints() {
+        let spline = BSpline::clamped(
+            3,
+            vec![
+                point(0.0, 0.0),
+                point(1.0, 2.0),
+                point(3.0, 2.0),
+                point(4.0, 0.0),
+                point(6.0, -1.0),
+            ],
+        );
+        assert_eq!(
+            spline.knots,
+            vec![0.0, 0.0, 0.0, 0.0, 0.5, 1.0, 1.0, 1.0, 1.0]
+        );
+        assert!(distance(spline.start().into(), point(0.0, 0.0).into()) < 1e-5);
+        assert!(distance(spline.end().into(), point(6.0, -1.0).into()) < 1e-5);
+    }
+
+    #[test]
+    fn test_single_span_is_bezier() {
+        let points = vec![
+            point(0.0, 0.0),
+            point(1.0, 3.0),
+            point(3.0, 3.0),
+            point(4.0, 0.0),
+        ];
+        let spline = BSpline::clamped(3, points.clone());
+        let bezier = CubicCurve {
+            start: points[0],
+            control1: points[1],
+            control2: points[2],
+            end: points[3],
+        };
+        for i in 0..=10 {
+            let t = i as Float / 10.0;
+            assert!(distance(spline.eval(t), bezier.eval(t)) < 1e-4);
+        }
+        let curves = spline.to_cubic_curves();
+        assert_eq!(curves.len(), 1);
+        assert!(distance(curves[0].control1.into(), points[1].into()) < 1e-4);
+        assert!(distance(curves[0].control2.into(), points[2].into()) < 1e-4);
+    }
+
+    #[test]
+    fn test_bspline_to_cubic_curves_exact() {
+        let spline = BSpline::clamped(
+            3,
+            vec![
+                point(0.0, 0.0),
+                point(1.0, 2.0),
+                point(3.0, 2.0),
+                point(4.0, 0.0),
+                point(6.0, -1.0),
+                point(7.0, 1.0),
+            ],
+        );
+        let path = spline.to_path();
+        assert_eq!(path.segments().len(), 3);
+        for (i, curve) in spline.to_cubic_curves().iter().enumerate() {
+            for j in 0..=4 {
+                let local = j as Float / 4.0;
+                let t = (i as Float + local) / 3.0;
+                assert!(distance(spline.eval(t), curve.eval(local)) < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_nurbs_circle_quadrant() {
+        let w = (0.5 as Float).sqrt();
+        let quadrant = Nurbs::clamped(
+            2,
+            vec![point(1.0, 0.0), point(1.0, 1.0), point(0.0, 1.0)],
+            vec![1.0, w, 1.0],
+        );
+        for i in 0..=10 {
+            let p = GlVec::from(quadrant.eval(i as Float / 10.0));
+            assert!((p.length() - 1.0).abs() < 1e-4);
+        }
+        let tangent = GlVec::from(quadrant.derivative(0.0));
+        assert!(tangent.x.abs() < 1e-4 && tangent.y > 0.0);
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+
+use crate::{Angle, BBox, Float, GlVec, Path, Point, Polyline};
+
+/// half of a rhombus, the apex `a` and the base `b`-`c` shared with its mirror
+#[derive(Debug, Clone, Copy)]
+struct Triangle {
+    thick: bool,
+    a: GlVec,
+    b: GlVec,
+    c: GlVec,
+}
+
+/// Penrose rhombus tiling of the bounds as closed paths
+///
+/// starts from a wheel of ten triangles around the center covering the bounds
+/// and deflates it `subdivisions` times, tiles that aren't fully inside are
+/// dropped and every tile has its `tile` meta set to `thin` or `thick`
+pub fn penrose_tiles(bounds: &BBox, subdivisions: usize) -> Vec<Path> {
+    let golden = (1.0 + (5.0 as Float).sqrt()) / 2.0;
+    let center = GlVec::from(bounds.center());
+    let tenth = Angle::PI / 10.0;
+    // the wheel is a decagon whose inner circle reaches the corners
+    let radius = bounds.width().hypot(bounds.height()) / 2.0 / tenth.cos();
+    if radius <= 0.0 {
+        return Vec::new();
+    }
+
+    let corner = |i: usize| {
+        let angle = tenth * (2 * i + 19) as Float;
+        let mut offset = GlVec::ZERO;
+        offset.x = radius * angle.cos();
+        offset.y = radius * angle.sin();
+        center + offset
+    };
+    let mut triangles: Vec<Triangle> = (0..10)
+        .map(|i| {
+            let (b, c) = (corner(i), corner(i + 1));
+            let (b, c) = if i % 2 == 0 { (c, b) } else { (b, c) };
+            Triangle {
+                thick: false,
+                a: center,
+                b,
+                c,
+            }
+        })
+        .collect();
+
+    for _ in 0..subdivisions {
+        triangles = triangles
+            .into_iter()
+            .flat_map(|Triangle { thick, a, b, c }| {
+                if thick {
+                    let q = b + (a - b) / golden;
+                    let r = b + (c - b) / golden;
+                    vec![
+                        Triangle {
+                            thick: true,
+                            a: r,
+                            b: c,
+                            c: a,
+                        },
+                        Triangle {
+                            thick: true,
+                            a: q,
+                            b: r,
+                            c: b,
+                        },
+                        Triangle {
+                            thick: false,
+                            a: r,
+                            b: q,
+                            c: a,
+                        },
+                    ]
+                } else {
+                    let p = a + (b - a) / golden;
+                    vec![
+                        Triangle {
+                            thick: false,
+                            a: c,
+                            b: p,
+                            c: b,
+                        },
+                        Triangle {
+                            thick: true,
+                            a: p,
+                            b: c,
+                            c: a,
+                        },
+                    ]
+                }
+            })
+            .collect();
+    }
+
+    // mirrored halves share their base, pair them up into rhombi
+    let key = |v: GlVec| ((v.x * 1e4).round() as i64, (v.y * 1e4).round() as i64);
+    let mut halves: HashMap<_, Triangle> = HashMap::new();
+    let mut tiles = Vec::new();
+    for triangle in triangles {
+        let (kb, kc) = (key(triangle.b), key(triangle.c));
+        let base = (triangle.thick, kb.min(kc), kb.max(kc));
+        let Some(other) = halves.remove(&base) else {
+            halves.insert(base, triangle);
+            continue;
+        };
+
+        let corners = [triangle.a, triangle.b, other.a, triangle.c, triangle.a];
+        if corners.iter().all(|v| bounds.contains((*v).into())) {
+            let points = corners.iter().map(|v| Point::from(*v)).collect();
+            let kind = if triangle.thick { "thick" } else { "thin" };
+            tiles.push(Path::from(Polyline(points)).with_meta("tile", kind));
+        }
+    }
+
+    tiles
+}
+
+#[cfg(test)]
+mod penrose_tests {
+    use super::*;
+    use crate::test_util::point;
+
+    #[test]
+    fn test_penrose_tiles() {
+        let bounds = BBox::new(point(-10.0, -10.0), point(10.0, 10.0));
+        let tiles = penrose_tiles(&bounds, 5);
+        assert!(tiles.len() > 50);
+
+        let golden = (1.0 + (5.0 as Float).sqrt()) / 2.0;
+        let (mut thin, mut thick) = (0.0, 0.0);
+        let sides = |tile: &Path| {
+            let corners = Polyline::from(tile).0;
+            assert_eq!(corners.len(), 5);
+            assert_eq!(corners[0], corners[4]);
+            corners
+                .windows(2)
+                .map(|w| GlVec::from(w[0]).distance(w[1].into()))
+                .collect::<Vec<_>>()
+        };
+        let side = sides(&tiles[0])[0];
+        for tile in &tiles {
+            for length in sides(tile) {
+                assert!((length - side).abs() / side < 1e-3);
+            }
+            match tile.get_meta("tile") {
+                Some("thin") => thin += 1.0,
+                Some("thick") => thick += 1.0,
+                kind => panic!("unexpected tile {kind:?}"),
+            }
+        }
+        // thick rhombi outnumber thin ones by the golden ratio
+        assert!((thick / thin - golden).abs() < 0.25);
+
+        // rhombi fill the plane without overlap
+        let area: Float = tiles.iter().map(|tile| tile.area()).sum();
+        assert!(area < 400.0 && area > 200.0);
+    }
+
+    #[test]
+    fn test_penrose_empty_bounds() {
+        let bounds = BBox::new(point(1.0, 1.0), point(1.0, 1.0));
+        assert!(penrose_tiles(&bounds, 3).is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{Float, Path, Point, VectorValuedFn};
+
+/// what was found under a point
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PickResult {
+    /// index of the path in the picked collection
+    pub path_index: usize,
+    /// index of the segment within the path
+    pub segment_index: usize,
+    /// distance from the point to the segment
+    pub distance: Float,
+}
+
+/// finds the segment closest to `point` within `tolerance`
+///
+/// distances are measured in the `xy` plane against flattened segments,
+/// when several paths are equally close the last one, drawn on top, wins
+pub fn pick(paths: &[Path], point: Point, tolerance: Float) -> Option<PickResult> {
+    let p = [point.x, point.y];
+    let mut best: Option<PickResult> = None;
+
+    for (path_index, path) in paths.iter().enumerate() {
+        for (segment_index, segment) in path.segments().iter().enumerate() {
+            let samples = segment.sample_optimal();
+            let distance = samples
+                .windows(2)
+                .map(|w| distance_to_segment(p, [w[0].x, w[0].y], [w[1].x, w[1].y]))
+                .fold(Float::INFINITY, Float::min);
+
+            if distance <= tolerance && best.is_none_or(|b| distance <= b.distance) {
+                best = Some(PickResult {
+                    path_index,
+                    segment_index,
+                    distance,
+                });
+            }
+        }
+    }
+
+    best
+}
+
+/// distance from `p` to the closest point of the `a`-`b` line segment
+pub(crate) fn distance_to_segment(p: [Float; 2], a: [Float; 2], b: [Float; 2]) -> Float {
+    let ab = [b[0] - a[0], b[1] - a[1]];
+    let ap = [p[0] - a[0], p[1] - a[1]];
+    let len_sq = ab[0] * ab[0] + ab[1] * ab[1];
+    let t = if len_sq == 0.0 {
+        0.0
+    } else {
+        ((ap[0] * ab[0] + ap[1] * ab[1]) / len_sq).clamp(0.0, 1.0)
+    };
+    (ap[0] - ab[0] * t).hypot(ap[1] - ab[1] * t)
+}
+
+#[cfg(test)]
+mod pick_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::Vector;
+
+    #[test]
+    fn test_pick() {
+        let paths = vec![
+            Path::rectangle(point(0.0, 0.0), Vector::from(point(10.0, 10.0))),
+            Path::rectangle(point(20.0, 0.0), Vector::from(point(10.0, 10.0))),
+        ];
+
+        assert_eq!(
+            pick(&paths, point(21.0, 5.0), 2.0),
+            Some(PickResult {
+                path_index: 1,
+                segment_index: 3,
+                distance: 1.0
+            })
+        );
+        assert_eq!(
+            pick(&paths, point(5.0, 0.5), 1.0).map(|r| (r.path_index, r.segment_index)),
+            Some((0, 0))
+        );
+        assert_eq!(pick(&paths, point(15.0, 5.0), 2.0), None);
+    }
+
+    #[test]
+    fn test_distance_to_segment() {
+        assert_eq!(distance_to_segment([0.0, 1.0], [0.0, 0.0], [2.0, 0.0]), 1.0);
+        assert_eq!(distance_to_segment([3.0, 4.0], [0.0, 0.0], [0.0, 0.0]), 5.0);
+        assert_eq!(distance_to_segment([5.0, 0.0], [0.0, 0.0], [2.0, 0.0]), 3.0);
+    }
+}
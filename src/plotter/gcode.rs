@@ -0,0 +1,62 @@
+use std::fmt::Write;
+
+use crate::Path;
+
+use super::{plotter_polylines, PlotterOptions};
+
+/// writes paths as G-code for pen plotters
+///
+/// travel happens with `G0` at `options.travel_rate`
+/// and drawing with `G1` at `options.feed_rate`,
+/// `options.pen_up` and `options.pen_down` are emitted around every polyline
+pub fn to_gcode(paths: &[Path], options: &PlotterOptions) -> String {
+    let mut out = String::new();
+
+    writeln!(out, "G21").unwrap();
+    writeln!(out, "G90").unwrap();
+    writeln!(out, "{}", options.pen_up).unwrap();
+
+    for polyline in plotter_polylines(paths, options) {
+        let [x, y] = polyline[0];
+        writeln!(out, "G0 X{x:.3} Y{y:.3} F{:.0}", options.travel_rate).unwrap();
+        writeln!(out, "{}", options.pen_down).unwrap();
+
+        for [x, y] in polyline.iter().skip(1) {
+            writeln!(out, "G1 X{x:.3} Y{y:.3} F{:.0}", options.feed_rate).unwrap();
+        }
+
+        writeln!(out, "{}", options.pen_up).unwrap();
+    }
+
+    writeln!(out, "G0 X0 Y0 F{:.0}", options.travel_rate).unwrap();
+    writeln!(out, "M2").unwrap();
+
+    out
+}
+
+#[cfg(test)]
+mod gcode_tests {
+    use super::*;
+    use crate::plotter::plotter_tests::line;
+
+    #[test]
+    fn test_to_gcode() {
+        let paths = vec![line(1.0, 2.0, 3.0, 4.0)];
+        let gcode = to_gcode(&paths, &PlotterOptions::default());
+
+        assert_eq!(
+            gcode.lines().collect::<Vec<_>>(),
+            vec![
+                "G21",
+                "G90",
+                "G0 Z1",
+                "G0 X1.000 Y2.000 F3000",
+                "G1 Z0",
+                "G1 X3.000 Y4.000 F1000",
+                "G0 Z1",
+                "G0 X0 Y0 F3000",
+                "M2",
+            ]
+        );
+    }
+}
@@ -0,0 +1,53 @@
+use std::fmt::Write;
+
+use crate::Path;
+
+use super::{plotter_polylines, PlotterOptions};
+
+/// writes paths as HPGL commands
+///
+/// coordinates are multiplied by `options.scale` and rounded to
+/// whole plotter units, HPGL plotters usually use 40 units per mm
+pub fn to_hpgl(paths: &[Path], options: &PlotterOptions) -> String {
+    let mut out = String::from("IN;SP1;");
+
+    for polyline in plotter_polylines(paths, options) {
+        let [x, y] = polyline[0];
+        write!(out, "PU{},{};", x.round(), y.round()).unwrap();
+
+        let coords: Vec<String> = polyline
+            .iter()
+            .skip(1)
+            .map(|[x, y]| format!("{},{}", x.round(), y.round()))
+            .collect();
+
+        if coords.is_empty() {
+            write!(out, "PD;").unwrap();
+        } else {
+            write!(out, "PD{};", coords.join(",")).unwrap();
+        }
+    }
+
+    out.push_str("PU;SP0;");
+    out
+}
+
+#[cfg(test)]
+mod hpgl_tests {
+    use super::*;
+    use crate::plotter::plotter_tests::line;
+
+    #[test]
+    fn test_to_hpgl() {
+        let paths = vec![line(1.0, 1.0, 2.0, 1.0), line(0.0, 0.0, 0.0, 1.0)];
+        let options = PlotterOptions {
+            scale: 40.0,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            to_hpgl(&paths, &options),
+            "IN;SP1;PU0,0;PD0,40;PU40,40;PD80,40;PU;SP0;"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+mod gcode;
+mod hpgl;
+mod order;
+
+pub use gcode::*;
+pub use hpgl::*;
+pub use order::*;
+
+use crate::{Float, Path, Vector, VectorValuedFn};
+
+/// settings shared by plotter output formats
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlotterOptions {
+    /// plotter units per drawing unit
+    pub scale: Float,
+    /// feed rate of drawing moves in plotter units per minute
+    pub feed_rate: Float,
+    /// feed rate of travel moves in plotter units per minute
+    pub travel_rate: Float,
+    /// command lifting the pen, used by G-code output
+    pub pen_up: String,
+    /// command lowering the pen, used by G-code output
+    pub pen_down: String,
+    /// flattening tolerance in drawing units,
+    /// `None` uses [`VectorValuedFn::sample_optimal`]
+    pub tolerance: Option<Float>,
+    /// reorders paths and flips their direction
+    /// to shorten pen-up travel, see [`optimize_draw_order`]
+    pub optimize_travel: bool,
+}
+
+impl Default for PlotterOptions {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            feed_rate: 1000.0,
+            travel_rate: 3000.0,
+            pen_up: "G0 Z1".to_string(),
+            pen_down: "G1 Z0".to_string(),
+            tolerance: None,
+            optimize_travel: true,
+        }
+    }
+}
+
+/// flattens paths into polylines of plotter coordinates
+/// in the order they should be drawn
+pub(crate) fn plotter_polylines(paths: &[Path], options: &PlotterOptions) -> Vec<Vec<[Float; 2]>> {
+    let polylines: Vec<Vec<[Float; 2]>> = paths
+        .iter()
+        .map(|p| {
+            let samples = match options.tolerance {
+                Some(tolerance) => p.flattened_with_tolerance(tolerance),
+                None => p.sample_optimal(),
+            };
+            samples
+                .into_iter()
+                .map(|v: Vector| [v.x * options.scale, v.y * options.scale])
+                .collect::<Vec<_>>()
+        })
+        .filter(|p| !p.is_empty())
+        .collect();
+
+    if options.optimize_travel {
+        let ends: Vec<_> = polylines.iter().map(|p| (p[0], p[p.len() - 1])).collect();
+        let mut slots: Vec<Option<Vec<[Float; 2]>>> = polylines.into_iter().map(Some).collect();
+
+        plan_order(&ends, true)
+            .into_iter()
+            .map(|(i, reversed)| {
+                let mut polyline = slots[i].take().unwrap();
+                if reversed {
+                    polyline.reverse();
+                }
+                polyline
+            })
+            .collect()
+    } else {
+        polylines
+    }
+}
+
+#[cfg(test)]
+mod plotter_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::LineSegment;
+
+    pub(super) fn line(x0: Float, y0: Float, x1: Float, y1: Float) -> Path {
+        Path::new(vec![Box::new(LineSegment {
+            start: point(x0, y0),
+            end: point(x1, y1),
+        })])
+    }
+
+    #[test]
+    fn test_nearest_neighbor_order() {
+        let paths = vec![
+            line(10.0, 0.0, 20.0, 0.0),
+            line(1.0, 0.0, 0.0, 0.0),
+            line(2.0, 0.0, 9.0, 0.0),
+        ];
+        let polylines = plotter_polylines(&paths, &PlotterOptions::default());
+
+        assert_eq!(
+            polylines,
+            vec![
+                vec![[0.0, 0.0], [1.0, 0.0]],
+                vec![[2.0, 0.0], [9.0, 0.0]],
+                vec![[10.0, 0.0], [20.0, 0.0]],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_emission_order_kept() {
+        let paths = vec![line(10.0, 0.0, 20.0, 0.0), line(1.0, 0.0, 0.0, 0.0)];
+        let options = PlotterOptions {
+            optimize_travel: false,
+            scale: 2.0,
+            ..Default::default()
+        };
+        let polylines = plotter_polylines(&paths, &options);
+
+        assert_eq!(
+            polylines,
+            vec![vec![[20.0, 0.0], [40.0, 0.0]], vec![[2.0, 0.0], [0.0, 0.0]],]
+        );
+    }
+}
@@ -0,0 +1,273 @@
+use crate::{Float, Path, VectorValuedFn};
+
+/// upper bound of 2-opt improvement passes
+const MAX_PASSES: usize = 32;
+
+/// reorders paths to minimize pen travel between them
+///
+/// starts with a nearest neighbor tour from the origin and improves it with 2-opt,
+/// when `allow_flip` is set paths may also be reversed
+/// if that shortens the travel to them
+pub fn optimize_draw_order(paths: &mut Vec<Path>, allow_flip: bool) {
+    let (drawable, empty): (Vec<Path>, Vec<Path>) = std::mem::take(paths)
+        .into_iter()
+        .partition(|p| !p.segments().is_empty());
+
+    let ends: Vec<_> = drawable
+        .iter()
+        .map(|p| {
+            let (start, end) = (p.start(), p.end());
+            ([start.x, start.y], [end.x, end.y])
+        })
+        .collect();
+
+    let order = plan_order(&ends, allow_flip);
+    let mut slots: Vec<Option<Path>> = drawable.into_iter().map(Some).collect();
+
+    for (i, reversed) in order {
+        let mut path = slots[i].take().unwrap();
+        if reversed {
+            path.reverse();
+        }
+        paths.push(path);
+    }
+
+    paths.extend(empty);
+}
+
+/// plans the order of strokes given their start and end points,
+/// returns the index of every stroke and whether it's drawn backwards
+pub(crate) fn plan_order(
+    ends: &[([Float; 2], [Float; 2])],
+    allow_flip: bool,
+) -> Vec<(usize, bool)> {
+    let mut tour = nearest_neighbor(ends, allow_flip);
+    two_opt(ends, &mut tour, allow_flip);
+    tour
+}
+
+fn nearest_neighbor(ends: &[([Float; 2], [Float; 2])], allow_flip: bool) -> Vec<(usize, bool)> {
+    let mut remaining: Vec<usize> = (0..ends.len()).collect();
+    let mut tour = Vec::with_capacity(ends.len());
+    let mut pen = [0.0, 0.0];
+
+    while !remaining.is_empty() {
+        let mut best = (0, false, Float::INFINITY);
+
+        for (r, &i) in remaining.iter().enumerate() {
+            let (start, end) = ends[i];
+            let to_start = distance(pen, start);
+            if to_start < best.2 {
+                best = (r, false, to_start);
+            }
+            if allow_flip {
+                let to_end = distance(pen, end);
+                if to_end < best.2 {
+                    best = (r, true, to_end);
+                }
+            }
+        }
+
+        let i = remaining.swap_remove(best.0);
+        tour.push((i, best.1));
+        pen = stroke_end(ends, (i, best.1));
+    }
+
+    tour
+}
+
+fn two_opt(ends: &[([Float; 2], [Float; 2])], tour: &mut [(usize, bool)], allow_flip: bool) {
+    let n = tour.len();
+    let mut forward = vec![0.0; n];
+    let mut backward = vec![0.0; n];
+    if !allow_flip {
+        run_travel(ends, tour, &mut forward, &mut backward, 1);
+    }
+
+    for _ in 0..MAX_PASSES {
+        let mut improved = false;
+
+        for i in 0..n {
+            for j in i + 1..n {
+                let prev_end = if i == 0 {
+                    [0.0, 0.0]
+                } else {
+                    stroke_end(ends, tour[i - 1])
+                };
+                let next_start = tour.get(j + 1).map(|&s| stroke_start(ends, s));
+
+                let mut old = distance(prev_end, stroke_start(ends, tour[i]));
+                let mut new;
+
+                if allow_flip {
+                    // reversing the run also flips every stroke in it,
+                    // so only the edges at its boundaries change
+                    new = distance(prev_end, stroke_end(ends, tour[j]));
+                    if let Some(next_start) = next_start {
+                        old += distance(stroke_end(ends, tour[j]), next_start);
+                        new += distance(stroke_start(ends, tour[i]), next_start);
+                    }
+                } else {
+                    new = distance(prev_end, stroke_start(ends, tour[j]));
+                    if let Some(next_start) = next_start {
+                        old += distance(stroke_end(ends, tour[j]), next_start);
+                        new += distance(stroke_end(ends, tour[i]), next_start);
+                    }
+                    old += forward[j] - forward[i];
+                    new += backward[j] - backward[i];
+                }
+
+                if new < old - Float::EPSILON * old.max(1.0) {
+                    tour[i..=j].reverse();
+                    if allow_flip {
+                        for stroke in tour[i..=j].iter_mut() {
+                            stroke.1 = !stroke.1;
+                        }
+                    } else {
+                        run_travel(ends, tour, &mut forward, &mut backward, i);
+                    }
+                    improved = true;
+                }
+            }
+        }
+
+        if !improved {
+            break;
+        }
+    }
+}
+
+/// travel between consecutive strokes up to each position of the tour from `from` on,
+/// `forward` as drawn and `backward` with every pair swapped,
+/// so the inner travel of a reversed run is a difference of two entries
+fn run_travel(
+    ends: &[([Float; 2], [Float; 2])],
+    tour: &[(usize, bool)],
+    forward: &mut [Float],
+    backward: &mut [Float],
+    from: usize,
+) {
+    for k in from.max(1)..tour.len() {
+        let (a, b) = (tour[k - 1], tour[k]);
+        forward[k] = forward[k - 1] + distance(stroke_end(ends, a), stroke_start(ends, b));
+        backward[k] = backward[k - 1] + distance(stroke_end(ends, b), stroke_start(ends, a));
+    }
+}
+
+fn stroke_start(ends: &[([Float; 2], [Float; 2])], (i, reversed): (usize, bool)) -> [Float; 2] {
+    if reversed {
+        ends[i].1
+    } else {
+        ends[i].0
+    }
+}
+
+fn stroke_end(ends: &[([Float; 2], [Float; 2])], (i, reversed): (usize, bool)) -> [Float; 2] {
+    if reversed {
+        ends[i].0
+    } else {
+        ends[i].1
+    }
+}
+
+fn distance(a: [Float; 2], b: [Float; 2]) -> Float {
+    (a[0] - b[0]).hypot(a[1] - b[1])
+}
+
+#[cfg(test)]
+mod order_tests {
+    use super::*;
+    use crate::flow_field::lattice;
+    use crate::plotter::plotter_tests::line;
+
+    fn travel(paths: &[Path]) -> Float {
+        let mut pen = [0.0, 0.0];
+        let mut total = 0.0;
+        for p in paths {
+            let (start, end) = (p.start(), p.end());
+            total += distance(pen, [start.x, start.y]);
+            pen = [end.x, end.y];
+        }
+        total
+    }
+
+    #[test]
+    fn test_optimize_draw_order() {
+        let mut paths = vec![
+            line(10.0, 0.0, 20.0, 0.0),
+            line(1.0, 0.0, 0.0, 0.0),
+            line(2.0, 0.0, 9.0, 0.0),
+        ];
+        optimize_draw_order(&mut paths, true);
+
+        let starts: Vec<_> = paths.iter().map(|p| p.start().x).collect();
+        assert_eq!(starts, vec![0.0, 2.0, 10.0]);
+        assert_eq!(travel(&paths), 2.0);
+    }
+
+    #[test]
+    fn test_optimize_draw_order_without_flip() {
+        let mut paths = vec![
+            line(10.0, 0.0, 20.0, 0.0),
+            line(1.0, 0.0, 0.0, 0.0),
+            line(2.0, 0.0, 9.0, 0.0),
+        ];
+        optimize_draw_order(&mut paths, false);
+
+        let starts: Vec<_> = paths.iter().map(|p| p.start().x).collect();
+        assert_eq!(starts, vec![1.0, 2.0, 10.0]);
+    }
+
+    #[test]
+    fn test_two_opt_improves_greedy_tour() {
+        let ends = [
+            ([-1.0, 0.0], [-1.0, 0.0]),
+            ([2.0, 0.0], [2.0, 0.0]),
+            ([4.0, 0.0], [4.0, 0.0]),
+            ([-5.0, 0.0], [-5.0, 0.0]),
+        ];
+        let cost = |tour: &[(usize, bool)]| {
+            let mut pen = [0.0, 0.0];
+            let mut total = 0.0;
+            for &s in tour {
+                total += distance(pen, stroke_start(&ends, s));
+                pen = stroke_end(&ends, s);
+            }
+            total
+        };
+
+        let greedy = nearest_neighbor(&ends, false);
+        let planned = plan_order(&ends, false);
+
+        assert_eq!(cost(&greedy), 15.0);
+        assert_eq!(cost(&planned), 13.0);
+    }
+
+    #[test]
+    fn test_plan_order_many_strokes() {
+        let ends: Vec<_> = (0..400)
+            .map(|i| {
+                let r = |c| lattice(i, c, 5) * 100.0;
+                ([r(0), r(1)], [r(2), r(3)])
+            })
+            .collect();
+        let cost = |tour: &[(usize, bool)]| {
+            let mut pen = [0.0, 0.0];
+            let mut total = 0.0;
+            for &s in tour {
+                total += distance(pen, stroke_start(&ends, s));
+                pen = stroke_end(&ends, s);
+            }
+            total
+        };
+
+        for allow_flip in [false, true] {
+            let planned = plan_order(&ends, allow_flip);
+            let mut seen: Vec<usize> = planned.iter().map(|s| s.0).collect();
+            seen.sort();
+            assert_eq!(seen, (0..400).collect::<Vec<_>>());
+            assert!(allow_flip || planned.iter().all(|s| !s.1));
+            assert!(cost(&planned) < cost(&nearest_neighbor(&ends, allow_flip)));
+        }
+    }
+}
@@ -0,0 +1,265 @@
+use crate::{pick::distance_to_segment, Float, LineSegment, Point, VectorValuedFn};
+
+/// how far apart things may be and still count as touching
+///
+/// shared by intersection, containment and clipping so that
+/// near-tangent cases resolve the same way everywhere
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tolerance {
+    /// points closer than this to a line or edge are on it
+    pub distance: Float,
+}
+
+impl Tolerance {
+    /// only exactly collinear points are on a line
+    pub const EXACT: Self = Self { distance: 0.0 };
+
+    pub fn new(distance: Float) -> Self {
+        Self {
+            distance: distance.abs(),
+        }
+    }
+
+    /// side of the `a`-`b` line `c` is on,
+    /// [`Orientation::Collinear`] within the tolerance distance
+    pub fn orientation(&self, a: Point, b: Point, c: Point) -> Orientation {
+        let (dx, dy) = (b.x - a.x, b.y - a.y);
+        let reach = self.distance * dx.hypot(dy);
+        let det = dx * (c.y - a.y) - dy * (c.x - a.x);
+        if reach > 0.0 && det.abs() <= reach {
+            Orientation::Collinear
+        } else {
+            orient2d(a, b, c)
+        }
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Self { distance: 1e-5 }
+    }
+}
+
+/// turn made going from `a` through `b` to `c` in the `xy` plane
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear,
+}
+
+/// exact orientation of `c` relative to the `a`-`b` line in the `xy` plane
+///
+/// the determinant is evaluated in `f64` and, when rounding could flip its sign,
+/// recomputed with error free expansion arithmetic
+#[allow(clippy::unnecessary_cast)]
+pub fn orient2d(a: Point, b: Point, c: Point) -> Orientation {
+    let [ax, ay, bx, by, cx, cy] = [a.x, a.y, b.x, b.y, c.x, c.y].map(|v| v as f64);
+
+    let left = (ax - cx) * (by - cy);
+    let right = (ay - cy) * (bx - cx);
+    let det = left - right;
+    let bound = (3.0 + 16.0 * HALF_EPSILON) * HALF_EPSILON * (left.abs() + right.abs());
+    let det = if det.abs() > bound {
+        det
+    } else {
+        let mut expansion = Vec::with_capacity(12);
+        for (p, q) in [
+            (ax, by),
+            (-ax, cy),
+            (-ay, bx),
+            (ay, cx),
+            (bx, cy),
+            (-by, cx),
+        ] {
+            let (product, error) = two_product(p, q);
+            grow_expansion(&mut expansion, error);
+            grow_expansion(&mut expansion, product);
+        }
+        expansion.into_iter().rfind(|v| *v != 0.0).unwrap_or(0.0)
+    };
+
+    if det > 0.0 {
+        Orientation::CounterClockwise
+    } else if det < 0.0 {
+        Orientation::Clockwise
+    } else {
+        Orientation::Collinear
+    }
+}
+
+/// point where two line segments cross in the `xy` plane, `z` is taken from `a`
+///
+/// endpoints within the tolerance of the other segment count as touching,
+/// collinear overlaps have no single crossing point and give `None`
+pub fn line_intersection(a: &LineSegment, b: &LineSegment, tolerance: Tolerance) -> Option<Point> {
+    let o1 = tolerance.orientation(a.start, a.end, b.start);
+    let o2 = tolerance.orientation(a.start, a.end, b.end);
+    let o3 = tolerance.orientation(b.start, b.end, a.start);
+    let o4 = tolerance.orientation(b.start, b.end, a.end);
+
+    let apart = |p: Orientation, q: Orientation| p == q && p != Orientation::Collinear;
+    if apart(o1, o2) || apart(o3, o4) {
+        return None;
+    }
+    if [o1, o2, o3, o4]
+        .iter()
+        .all(|o| *o == Orientation::Collinear)
+    {
+        return None;
+    }
+
+    let (dax, day) = (a.end.x - a.start.x, a.end.y - a.start.y);
+    let (dbx, dby) = (b.end.x - b.start.x, b.end.y - b.start.y);
+    let denom = dax * dby - day * dbx;
+    let project =
+        |q: Point| ((q.x - a.start.x) * dax + (q.y - a.start.y) * day) / (dax * dax + day * day);
+    let t = match (o1, o2, o3, o4) {
+        (_, _, Orientation::Collinear, _) => 0.0,
+        (_, _, _, Orientation::Collinear) => 1.0,
+        (Orientation::Collinear, ..) => project(b.start),
+        (_, Orientation::Collinear, ..) => project(b.end),
+        _ if denom == 0.0 => return None,
+        _ => ((b.start.x - a.start.x) * dby - (b.start.y - a.start.y) * dbx) / denom,
+    };
+    Some(a.eval(t.clamp(0.0, 1.0)).into())
+}
+
+/// whether `point` is inside the closed ring of points in the `xy` plane,
+/// crossings are counted even-odd and points on an edge within the tolerance are inside
+pub fn ring_contains(ring: &[Point], point: Point, tolerance: Tolerance) -> bool {
+    let n = ring.len();
+    if n < 2 {
+        return false;
+    }
+
+    let edges = ring.iter().zip(ring.iter().cycle().skip(1)).take(n);
+    let p = [point.x, point.y];
+    let mut inside = false;
+    for (a, b) in edges {
+        if distance_to_segment(p, [a.x, a.y], [b.x, b.y]) <= tolerance.distance {
+            return true;
+        }
+        let crosses = match (a.y <= point.y, b.y <= point.y) {
+            (true, false) => orient2d(*a, *b, point) == Orientation::CounterClockwise,
+            (false, true) => orient2d(*a, *b, point) == Orientation::Clockwise,
+            _ => false,
+        };
+        inside ^= crosses;
+    }
+    inside
+}
+
+const HALF_EPSILON: f64 = f64::EPSILON / 2.0;
+
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let sum = a + b;
+    let b_virtual = sum - a;
+    let a_virtual = sum - b_virtual;
+    (sum, (a - a_virtual) + (b - b_virtual))
+}
+
+fn two_product(a: f64, b: f64) -> (f64, f64) {
+    let product = a * b;
+    (product, a.mul_add(b, -product))
+}
+
+/// adds `value` to a nonoverlapping expansion ordered by increasing magnitude
+fn grow_expansion(expansion: &mut Vec<f64>, value: f64) {
+    let mut q = value;
+    for component in expansion.iter_mut() {
+        let (sum, error) = two_sum(q, *component);
+        *component = error;
+        q = sum;
+    }
+    expansion.push(q);
+}
+
+#[cfg(test)]
+mod predicates_tests {
+    use super::*;
+    use crate::test_util::point;
+
+    fn segment(a: Point, b: Point) -> LineSegment {
+        LineSegment { start: a, end: b }
+    }
+
+    #[test]
+    fn test_orient2d() {
+        assert_eq!(
+            orient2d(point(0.0, 0.0), point(1.0, 0.0), point(0.0, 1.0)),
+            Orientation::CounterClockwise
+        );
+        assert_eq!(
+            orient2d(point(0.0, 0.0), point(1.0, 0.0), point(0.0, -1.0)),
+            Orientation::Clockwise
+        );
+
+        // one ulp off the diagonal is still on the right side
+        let (a, b) = (point(0.1, 0.1), point(0.7, 0.7));
+        for i in 1..64 {
+            let x = 0.1 + i as Float * 0.01;
+            let above = Float::from_bits(x.to_bits() + 1);
+            let below = Float::from_bits(x.to_bits() - 1);
+            assert_eq!(orient2d(a, b, point(x, x)), Orientation::Collinear);
+            assert_eq!(
+                orient2d(a, b, point(x, above)),
+                Orientation::CounterClockwise
+            );
+            assert_eq!(orient2d(a, b, point(x, below)), Orientation::Clockwise);
+        }
+    }
+
+    #[test]
+    fn test_line_intersection() {
+        let a = segment(point(0.0, 0.0), point(2.0, 2.0));
+        let b = segment(point(0.0, 2.0), point(2.0, 0.0));
+        assert_eq!(
+            line_intersection(&a, &b, Tolerance::EXACT),
+            Some(point(1.0, 1.0))
+        );
+
+        let apart = segment(point(3.0, 0.0), point(3.0, 5.0));
+        assert_eq!(line_intersection(&a, &apart, Tolerance::EXACT), None);
+
+        let overlapping = segment(point(1.0, 1.0), point(3.0, 3.0));
+        assert_eq!(line_intersection(&a, &overlapping, Tolerance::EXACT), None);
+    }
+
+    #[test]
+    fn test_near_tangent_is_stable() {
+        let a = segment(point(0.0, 0.0), point(10.0, 0.0));
+        let tolerance = Tolerance::new(1e-3);
+        for i in 0..100 {
+            let gap = i as Float * 1e-6;
+            let touching = segment(point(5.0, gap), point(6.0, 1.0));
+            assert_eq!(
+                line_intersection(&a, &touching, tolerance),
+                Some(point(5.0, 0.0))
+            );
+        }
+
+        let clear = segment(point(5.0, 1e-2), point(6.0, 1.0));
+        assert_eq!(line_intersection(&a, &clear, tolerance), None);
+    }
+
+    #[test]
+    fn test_ring_contains() {
+        let ring = [
+            point(0.0, 0.0),
+            point(4.0, 0.0),
+            point(4.0, 4.0),
+            point(0.0, 4.0),
+        ];
+
+        assert!(ring_contains(&ring, point(2.0, 2.0), Tolerance::EXACT));
+        assert!(!ring_contains(&ring, point(5.0, 2.0), Tolerance::EXACT));
+        assert!(ring_contains(&ring, point(4.0, 2.0), Tolerance::EXACT));
+        assert!(ring_contains(
+            &ring,
+            point(4.0005, 2.0),
+            Tolerance::new(1e-3)
+        ));
+        assert!(!ring_contains(&ring, point(4.0005, 2.0), Tolerance::EXACT));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::Float;
+
+/// golden section `φ`
+pub const GOLDEN_RATIO: Float = 1.618_033_988_749_895_f64 as Float;
+
+/// musical interval ratios for [`ratio_radii`]
+pub const OCTAVE: Float = 2.0;
+pub const PERFECT_FIFTH: Float = 3.0 / 2.0;
+pub const PERFECT_FOURTH: Float = 4.0 / 3.0;
+pub const MAJOR_THIRD: Float = 5.0 / 4.0;
+pub const MINOR_THIRD: Float = 6.0 / 5.0;
+
+/// `n` radii growing by `ratio` up to `outer`, from the innermost
+pub fn ratio_radii(outer: Float, ratio: Float, n: usize) -> Vec<Float> {
+    (0..n)
+        .map(|i| outer / ratio.powi((n - 1 - i) as i32))
+        .collect()
+}
+
+/// `n` radii in golden section up to `outer`, from the innermost
+pub fn golden_radii(outer: Float, n: usize) -> Vec<Float> {
+    ratio_radii(outer, GOLDEN_RATIO, n)
+}
+
+/// `n` radii following the harmonic series `outer / k`, from the innermost
+pub fn harmonic_radii(outer: Float, n: usize) -> Vec<Float> {
+    (1..=n).rev().map(|k| outer / k as Float).collect()
+}
+
+/// boundaries of `n` rings of equal area between `inner` and `outer`,
+/// `n + 1` radii starting with `inner`
+pub fn equal_area_radii(inner: Float, outer: Float, n: usize) -> Vec<Float> {
+    let (inner_sq, outer_sq) = (inner * inner, outer * outer);
+    (0..=n)
+        .map(|k| (inner_sq + (outer_sq - inner_sq) * k as Float / n.max(1) as Float).sqrt())
+        .collect()
+}
+
+#[cfg(test)]
+mod proportions_tests {
+    use super::*;
+
+    fn assert_all_close(a: &[Float], b: &[Float]) {
+        assert_eq!(a.len(), b.len());
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() < 1e-3, "{a} != {b}");
+        }
+    }
+
+    #[test]
+    fn test_ratio_radii() {
+        assert_all_close(&ratio_radii(8.0, OCTAVE, 4), &[1.0, 2.0, 4.0, 8.0]);
+        let golden = golden_radii(100.0, 3);
+        assert_all_close(&golden, &[38.197, 61.803, 100.0]);
+        // each ring is as wide as the radius two steps in
+        assert!(((golden[2] - golden[1]) - golden[0]).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_harmonic_radii() {
+        assert_all_close(&harmonic_radii(12.0, 4), &[3.0, 4.0, 6.0, 12.0]);
+    }
+
+    #[test]
+    fn test_equal_area_radii() {
+        let radii = equal_area_radii(1.0, 3.0, 4);
+        assert_eq!(radii.len(), 5);
+        assert_eq!(radii[0], 1.0);
+        assert_eq!(radii[4], 3.0);
+        let areas: Vec<Float> = radii
+            .windows(2)
+            .map(|w| w[1] * w[1] - w[0] * w[0])
+            .collect();
+        assert_all_close(&areas, &[2.0; 4]);
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{clip_paths, contour, flow_field::lattice, BBox, DistanceField, Float, Path};
+
+/// level of the second chemical traced into contours
+const CONTOUR_LEVEL: Float = 0.25;
+
+/// Gray-Scott reaction-diffusion on a grid wrapping around its edges
+///
+/// `u` is fed in and consumed by `v`, which is killed off at its own rate,
+/// the defaults grow labyrinthine coral patterns
+#[derive(Debug, Clone, PartialEq)]
+pub struct GrayScott {
+    pub width: usize,
+    pub height: usize,
+    pub feed: Float,
+    pub kill: Float,
+    pub diffusion_u: Float,
+    pub diffusion_v: Float,
+    /// row by row with `y` pointing up
+    pub u: Vec<Float>,
+    pub v: Vec<Float>,
+}
+
+impl GrayScott {
+    /// grid full of `u` and empty of `v`
+    pub fn new(width: usize, height: usize) -> Self {
+        let (width, height) = (width.max(1), height.max(1));
+        Self {
+            width,
+            height,
+            feed: 0.0545,
+            kill: 0.062,
+            diffusion_u: 1.0,
+            diffusion_v: 0.5,
+            u: vec![1.0; width * height],
+            v: vec![0.0; width * height],
+        }
+    }
+
+    pub fn with_rates(mut self, feed: Float, kill: Float) -> Self {
+        self.feed = feed;
+        self.kill = kill;
+        self
+    }
+
+    /// drops square spots of `v` on about one in ten 4 by 4 blocks,
+    /// the same seed always gives the same spots
+    pub fn scatter(&mut self, seed: u32) {
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if lattice(x as i32 / 4, y as i32 / 4, seed) > 0.9 {
+                    let i = y * self.width + x;
+                    self.u[i] = 0.5;
+                    self.v[i] = 1.0;
+                }
+            }
+        }
+    }
+
+    /// advances the reaction by `steps` unit time steps
+    pub fn step(&mut self, steps: usize) {
+        let (width, height) = (self.width, self.height);
+        let mut next_u = self.u.clone();
+        let mut next_v = self.v.clone();
+
+        for _ in 0..steps {
+            for y in 0..height {
+                for x in 0..width {
+                    let i = y * width + x;
+                    let (mut lu, mut lv) = (-self.u[i], -self.v[i]);
+                    // 3 by 3 laplacian, edges weigh 0.2 and corners 0.05
+                    for (dx, dy, weight) in NEIGHBOURS {
+                        let nx = (x + width).wrapping_add_signed(dx) % width;
+                        let ny = (y + height).wrapping_add_signed(dy) % height;
+                        let n = ny * width + nx;
+                        lu += weight * self.u[n];
+                        lv += weight * self.v[n];
+                    }
+
+                    let (u, v) = (self.u[i], self.v[i]);
+                    let reaction = u * v * v;
+                    next_u[i] = (u + self.diffusion_u * lu - reaction + self.feed * (1.0 - u))
+                        .clamp(0.0, 1.0);
+                    next_v[i] = (v + self.diffusion_v * lv + reaction
+                        - (self.kill + self.feed) * v)
+                        .clamp(0.0, 1.0);
+                }
+            }
+            std::mem::swap(&mut self.u, &mut next_u);
+            std::mem::swap(&mut self.v, &mut next_v);
+        }
+    }
+
+    /// concentration of `v` as a field over the bounds, negated so that
+    /// contours of [`contour`] enclose where `v` is high
+    pub fn to_field(&self, bounds: BBox) -> DistanceField {
+        DistanceField {
+            width: self.width,
+            height: self.height,
+            bounds,
+            values: self.v.iter().map(|v| -v).collect(),
+        }
+    }
+}
+
+const NEIGHBOURS: [(isize, isize, Float); 8] = [
+    (-1, 0, 0.2),
+    (1, 0, 0.2),
+    (0, -1, 0.2),
+    (0, 1, 0.2),
+    (-1, -1, 0.05),
+    (1, -1, 0.05),
+    (-1, 1, 0.05),
+    (1, 1, 0.05),
+];
+
+/// organic labyrinth filling the closed outline
+///
+/// grows a [`GrayScott`] pattern for `steps` on a grid with `resolution`
+/// square cells along the longer side of the outline bounds, then traces
+/// where `v` crosses a quarter and clips the contours to the outline
+pub fn reaction_diffusion(outline: &Path, resolution: usize, steps: usize, seed: u32) -> Vec<Path> {
+    let Some(bounds) = outline.bounds() else {
+        return Vec::new();
+    };
+    let resolution = resolution.max(1);
+    let cell = bounds.width().max(bounds.height()).max(Float::EPSILON) / resolution as Float;
+    let width = (bounds.width() / cell).ceil().max(1.0) as usize;
+    let height = (bounds.height() / cell).ceil().max(1.0) as usize;
+
+    let mut reaction = GrayScott::new(width, height);
+    reaction.scatter(seed);
+    reaction.step(steps);
+
+    let mut max = bounds.min;
+    max.x += width as Float * cell;
+    max.y += height as Float * cell;
+    let field = reaction.to_field(BBox::new(bounds.min, max));
+    clip_paths(&contour(&field, -CONTOUR_LEVEL), outline)
+}
+
+#[cfg(test)]
+mod reaction_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::VectorValuedFn;
+
+    #[test]
+    fn test_gray_scott_grows() {
+        let mut reaction = GrayScott::new(32, 32);
+        reaction.scatter(3);
+        let seeded = reaction.v.iter().filter(|v| **v > 0.5).count();
+        assert!(seeded > 0);
+
+        reaction.step(400);
+        assert!(reaction
+            .u
+            .iter()
+            .chain(reaction.v.iter())
+            .all(|c| (0.0..=1.0).contains(c)));
+        let grown = reaction.v.iter().filter(|v| **v > CONTOUR_LEVEL).count();
+        assert!(grown > seeded);
+
+        let mut again = GrayScott::new(32, 32);
+        again.scatter(3);
+        again.step(400);
+        assert_eq!(again, reaction);
+    }
+
+    #[test]
+    fn test_reaction_diffusion_in_circle() {
+        let outline = Path::circle(point(0.0, 0.0), 10.0);
+        let paths = reaction_diffusion(&outline, 40, 400, 3);
+        assert!(!paths.is_empty());
+
+        for path in &paths {
+            for v in path.samples_optimal() {
+                assert!(v.x.hypot(v.y) <= 10.01);
+            }
+        }
+        assert!(reaction_diffusion(&Path::default(), 40, 10, 3).is_empty());
+    }
+}
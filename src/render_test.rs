@@ -0,0 +1,211 @@
+use std::{fs, io, path::PathBuf};
+
+use pix::{gray::SGray8, Raster};
+
+use crate::{pick::distance_to_segment, BBox, Float, Path, VectorValuedFn};
+
+/// share of the blurred image allowed to differ before a snapshot fails
+pub const RENDER_DIFF_THRESHOLD: Float = 0.01;
+
+/// strokes the flattened paths one pixel wide, black on white,
+/// with `viewport` filling the raster and `y` pointing up
+pub fn render_to_raster(
+    paths: &[Path],
+    viewport: &BBox,
+    width: u32,
+    height: u32,
+) -> Raster<SGray8> {
+    let mut raster = Raster::<SGray8>::with_color(width, height, SGray8::new(255));
+    if viewport.width() <= 0.0 || viewport.height() <= 0.0 {
+        return raster;
+    }
+
+    let (sx, sy) = (
+        width as Float / viewport.width(),
+        height as Float / viewport.height(),
+    );
+    let to_pixel = |x: Float, y: Float| {
+        [
+            (x - viewport.min.x) * sx,
+            height as Float - (y - viewport.min.y) * sy,
+        ]
+    };
+
+    for path in paths {
+        let points: Vec<[Float; 2]> = path.samples_optimal().map(|v| to_pixel(v.x, v.y)).collect();
+        for w in points.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            let x0 = (a[0].min(b[0]) - 1.0).floor().max(0.0) as u32;
+            let y0 = (a[1].min(b[1]) - 1.0).floor().max(0.0) as u32;
+            let x1 = ((a[0].max(b[0]) + 1.0).ceil() as u32).min(width);
+            let y1 = ((a[1].max(b[1]) + 1.0).ceil() as u32).min(height);
+            for y in y0..y1 {
+                for x in x0..x1 {
+                    let center = [x as Float + 0.5, y as Float + 0.5];
+                    if distance_to_segment(center, a, b) <= 0.5 {
+                        *raster.pixel_mut(x as i32, y as i32) = SGray8::new(0);
+                    }
+                }
+            }
+        }
+    }
+
+    raster
+}
+
+/// perceptual distance between two rasters of the same size, from 0 to 1
+///
+/// both are blurred over 3 by 3 pixels first so strokes moved by less
+/// than a pixel barely count, rasters of different sizes are fully apart
+pub fn render_diff(a: &Raster<SGray8>, b: &Raster<SGray8>) -> Float {
+    if a.width() != b.width() || a.height() != b.height() {
+        return 1.0;
+    }
+    let (width, height) = (a.width() as usize, a.height() as usize);
+    if width == 0 || height == 0 {
+        return 0.0;
+    }
+
+    let (a, b) = (
+        blurred(a.as_u8_slice(), width, height),
+        blurred(b.as_u8_slice(), width, height),
+    );
+    let total: Float = a.iter().zip(b.iter()).map(|(a, b)| (a - b).abs()).sum();
+    total / (width * height) as Float
+}
+
+/// renders the paths and compares them with the stored snapshot `name`,
+/// panics when they differ by more than [`RENDER_DIFF_THRESHOLD`]
+///
+/// snapshots are binary PGM files in `render_snapshots` of the crate under test,
+/// missing ones are written, set `UPDATE_RENDER_SNAPSHOTS` to overwrite them all
+pub fn assert_render_snapshot(name: &str, paths: &[Path], viewport: &BBox) {
+    let dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_default()
+        .join("render_snapshots");
+    let raster = render_to_raster(paths, viewport, 128, 128);
+    let update = std::env::var_os("UPDATE_RENDER_SNAPSHOTS").is_some();
+
+    match check_snapshot(&dir.join(format!("{name}.pgm")), &raster, update) {
+        Ok(diff) if diff <= RENDER_DIFF_THRESHOLD => {}
+        Ok(diff) => panic!("render snapshot {name} differs by {diff}"),
+        Err(err) => panic!("render snapshot {name}: {err}"),
+    }
+}
+
+/// difference from the stored snapshot, writing it first when missing or `update` is set
+fn check_snapshot(
+    file: &std::path::Path,
+    raster: &Raster<SGray8>,
+    update: bool,
+) -> io::Result<Float> {
+    if update || !file.exists() {
+        if let Some(dir) = file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(file, write_pgm(raster))?;
+        return Ok(0.0);
+    }
+
+    let stored = read_pgm(&fs::read(file)?)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "not a binary PGM"))?;
+    Ok(render_diff(&stored, raster))
+}
+
+fn write_pgm(raster: &Raster<SGray8>) -> Vec<u8> {
+    let mut bytes = format!("P5\n{} {}\n255\n", raster.width(), raster.height()).into_bytes();
+    bytes.extend_from_slice(raster.as_u8_slice());
+    bytes
+}
+
+fn read_pgm(bytes: &[u8]) -> Option<Raster<SGray8>> {
+    // magic, width, height and max value each end with one whitespace byte
+    let mut fields = Vec::with_capacity(4);
+    let mut start = 0;
+    for (i, byte) in bytes.iter().enumerate() {
+        if byte.is_ascii_whitespace() {
+            fields.push(std::str::from_utf8(&bytes[start..i]).ok()?);
+            start = i + 1;
+            if fields.len() == 4 {
+                break;
+            }
+        }
+    }
+
+    let [magic, width, height, max] = fields[..] else {
+        return None;
+    };
+    let (width, height): (u32, u32) = (width.parse().ok()?, height.parse().ok()?);
+    let data = &bytes[start..];
+    (magic == "P5" && max == "255" && data.len() == (width * height) as usize)
+        .then(|| Raster::with_u8_buffer(width, height, data.to_vec()))
+}
+
+/// darkness averaged over each pixel's 3 by 3 neighbourhood
+fn blurred(pixels: &[u8], width: usize, height: usize) -> Vec<Float> {
+    let mut out = vec![0.0; width * height];
+    for y in 0..height {
+        for x in 0..width {
+            let (mut sum, mut count) = (0.0, 0.0);
+            for ny in y.saturating_sub(1)..(y + 2).min(height) {
+                for nx in x.saturating_sub(1)..(x + 2).min(width) {
+                    sum += 1.0 - pixels[ny * width + nx] as Float / 255.0;
+                    count += 1.0;
+                }
+            }
+            out[y * width + x] = sum / count;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod render_test_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::Vector;
+
+    fn viewport() -> BBox {
+        BBox::new(point(0.0, 0.0), point(32.0, 32.0))
+    }
+
+    #[test]
+    fn test_render_to_raster() {
+        let square = Path::rectangle(point(8.0, 8.0), Vector::from(point(16.0, 16.0)));
+        let raster = render_to_raster(&[square], &viewport(), 32, 32);
+
+        assert_eq!(raster.pixel(8, 16), SGray8::new(0));
+        assert_eq!(raster.pixel(16, 7), SGray8::new(0));
+        assert_eq!(raster.pixel(16, 16), SGray8::new(255));
+        assert_eq!(raster.pixel(2, 2), SGray8::new(255));
+    }
+
+    #[test]
+    fn test_render_diff() {
+        let square = |x: Float| Path::rectangle(point(x, 8.0), Vector::from(point(16.0, 16.0)));
+        let a = render_to_raster(&[square(8.0)], &viewport(), 32, 32);
+        let nudged = render_to_raster(&[square(8.4)], &viewport(), 32, 32);
+        let moved = render_to_raster(&[square(12.0)], &viewport(), 32, 32);
+
+        assert_eq!(render_diff(&a, &a), 0.0);
+        assert!(render_diff(&a, &nudged) < render_diff(&a, &moved));
+        assert_eq!(render_diff(&a, &Raster::with_clear(8, 8)), 1.0);
+    }
+
+    #[test]
+    fn test_snapshot_round_trip() {
+        let file = std::env::temp_dir()
+            .join(format!("mandala-render-{}", std::process::id()))
+            .join("square.pgm");
+        let square = Path::rectangle(point(8.0, 8.0), Vector::from(point(16.0, 16.0)));
+        let raster = render_to_raster(&[square], &viewport(), 32, 32);
+        let blank = render_to_raster(&[], &viewport(), 32, 32);
+
+        assert_eq!(check_snapshot(&file, &raster, false).unwrap(), 0.0);
+        assert_eq!(check_snapshot(&file, &raster, false).unwrap(), 0.0);
+        assert!(check_snapshot(&file, &blank, false).unwrap() > RENDER_DIFF_THRESHOLD);
+
+        fs::remove_dir_all(file.parent().unwrap()).unwrap();
+    }
+}
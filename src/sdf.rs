@@ -0,0 +1,134 @@
+use crate::{
+    pick::distance_to_segment, ring_contains, BBox, Float, Path, Point, Tolerance, VectorValuedFn,
+};
+
+/// cells of empty margin kept around the paths so contours close
+const MARGIN_CELLS: Float = 2.0;
+
+/// signed distances to paths sampled on a grid in the `xy` plane,
+/// negative inside closed paths
+#[derive(Debug, Clone, PartialEq)]
+pub struct DistanceField {
+    pub width: usize,
+    pub height: usize,
+    /// area covered by the grid, samples sit at cell centers
+    pub bounds: BBox,
+    /// row by row from `bounds.min` with `y` pointing up
+    pub values: Vec<Float>,
+}
+
+impl DistanceField {
+    /// field of the union of the flattened paths with `resolution` cells
+    /// along the longer side of their bounds, `None` when there are no points
+    ///
+    /// closed paths are inside by the even-odd rule of all of them,
+    /// open paths have no inside
+    pub fn from_paths(paths: &[Path], resolution: usize) -> Option<Self> {
+        let polylines: Vec<Vec<Point>> = paths
+            .iter()
+            .map(|p| p.samples_optimal().map(Point::from).collect::<Vec<_>>())
+            .filter(|points| !points.is_empty())
+            .collect();
+        let bounds = BBox::from_points(polylines.iter().flatten().map(|p| (*p).into()))?;
+
+        let resolution = resolution.max(1);
+        let cell = bounds.width().max(bounds.height()).max(Float::EPSILON)
+            / (resolution as Float - 2.0 * MARGIN_CELLS).max(1.0);
+        let bounds = bounds.expanded(cell * MARGIN_CELLS);
+        let width = (bounds.width() / cell).ceil().max(1.0) as usize;
+        let height = (bounds.height() / cell).ceil().max(1.0) as usize;
+
+        let rings: Vec<&[Point]> = polylines
+            .iter()
+            .filter(|points| points.len() > 2 && points.first() == points.last())
+            .map(|points| &points[..points.len() - 1])
+            .collect();
+
+        let mut values = Vec::with_capacity(width * height);
+        for y in 0..height {
+            for x in 0..width {
+                let mut p = bounds.min;
+                p.x += (x as Float + 0.5) * cell;
+                p.y += (y as Float + 0.5) * cell;
+
+                let distance = polylines
+                    .iter()
+                    .flat_map(|points| points.windows(2))
+                    .map(|w| distance_to_segment([p.x, p.y], [w[0].x, w[0].y], [w[1].x, w[1].y]))
+                    .chain(
+                        polylines
+                            .iter()
+                            .filter(|l| l.len() == 1)
+                            .map(|l| (l[0].x - p.x).hypot(l[0].y - p.y)),
+                    )
+                    .fold(Float::INFINITY, Float::min);
+                let inside = rings
+                    .iter()
+                    .filter(|ring| ring_contains(ring, p, Tolerance::EXACT))
+                    .count()
+                    % 2
+                    == 1;
+                values.push(if inside { -distance } else { distance });
+            }
+        }
+
+        let mut max = bounds.min;
+        max.x += width as Float * cell;
+        max.y += height as Float * cell;
+        Some(Self {
+            width,
+            height,
+            bounds: BBox::new(bounds.min, max),
+            values,
+        })
+    }
+
+    /// distance at the cell, `x` to the right and `y` up
+    pub fn get(&self, x: usize, y: usize) -> Float {
+        self.values[y * self.width + x]
+    }
+
+    /// side of a square cell
+    pub fn cell_size(&self) -> Float {
+        self.bounds.width() / self.width as Float
+    }
+}
+
+impl Path {
+    /// signed distance field of the path, see [`DistanceField::from_paths`]
+    pub fn to_sdf(&self, resolution: usize) -> Option<DistanceField> {
+        DistanceField::from_paths(std::slice::from_ref(self), resolution)
+    }
+}
+
+#[cfg(test)]
+mod sdf_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{Polyline, Vector};
+
+    #[test]
+    fn test_square_sdf() {
+        let square = Path::rectangle(point(0.0, 0.0), Vector::from(point(16.0, 16.0)));
+        let field = square.to_sdf(20).unwrap();
+
+        assert_eq!((field.width, field.height), (20, 20));
+        assert_eq!(field.cell_size(), 1.0);
+        assert_eq!((field.bounds.min.x, field.bounds.min.y), (-2.0, -2.0));
+        // cell centers are half a cell off the grid lines
+        assert_eq!(field.get(10, 10), -7.5);
+        assert_eq!(field.get(0, 10), 1.5);
+        assert_eq!(field.get(2, 2), -0.5);
+        assert!(field.values.iter().filter(|v| **v < 0.0).count() == 16 * 16);
+    }
+
+    #[test]
+    fn test_open_and_empty() {
+        let line: Path = Polyline(vec![point(0.0, 0.0), point(8.0, 0.0)]).into();
+        let field = line.to_sdf(12).unwrap();
+
+        assert!(field.values.iter().all(|v| *v >= 0.0));
+        assert!(field.values.iter().any(|v| *v <= 0.5));
+        assert_eq!(Path::default().to_sdf(12), None);
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{sampled_length, Angle, Float, GlVec, Point, Vector, VectorValuedFn};
+
+/// wraps the `xy` plane around a sphere, the polar radius becomes the polar angle
+/// measured from the pole above `center`
+///
+/// `plane_radius` in the plane reaches `coverage` on the sphere,
+/// a quarter turn makes a dome and half a turn covers the whole sphere
+#[derive(Debug, Clone, Copy)]
+pub struct SphereMapping {
+    pub center: Point,
+    pub radius: Float,
+    pub plane_radius: Float,
+    pub coverage: Angle,
+}
+
+impl SphereMapping {
+    /// hemisphere over the disc of `radius`
+    pub fn dome(center: Point, radius: Float) -> Self {
+        Self {
+            center,
+            radius,
+            plane_radius: radius,
+            coverage: Angle::FRAC_PI_2,
+        }
+    }
+
+    pub fn map_point(&self, point: Vector) -> Vector {
+        let p = GlVec::from(point) - GlVec::from(self.center);
+        let polar = p.x.hypot(p.y) / self.plane_radius * self.coverage.to_radians();
+        let azimuth = p.y.atan2(p.x);
+        let on_sphere = GlVec::new(
+            polar.sin() * azimuth.cos(),
+            polar.sin() * azimuth.sin(),
+            polar.cos(),
+        );
+        (GlVec::from(self.center) + on_sphere * self.radius).into()
+    }
+
+    /// wraps the source around the sphere
+    pub fn wrap<F: VectorValuedFn>(self, source: F) -> Spherical<F> {
+        Spherical {
+            mapping: self,
+            source,
+        }
+    }
+}
+
+/// source wrapped around a sphere by [`SphereMapping`]
+#[derive(Debug, Clone, Copy)]
+pub struct Spherical<F> {
+    pub mapping: SphereMapping,
+    pub source: F,
+}
+
+impl<F: VectorValuedFn> VectorValuedFn for Spherical<F> {
+    fn eval(&self, t: Float) -> Vector {
+        self.mapping.map_point(self.source.eval(t))
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+#[cfg(test)]
+mod sphere_tests {
+    use super::*;
+    use crate::{project, Camera, LineSegment, SweepArc};
+
+    fn point(x: Float, y: Float, z: Float) -> Point {
+        Point { x, y, z }
+    }
+
+    fn close(a: Vector, b: Vector) -> bool {
+        (GlVec::from(a) - GlVec::from(b)).length() < 1e-4
+    }
+
+    #[test]
+    fn test_dome_mapping() {
+        let dome = SphereMapping::dome(point(0.0, 0.0, 0.0), 10.0);
+        assert!(close(
+            dome.map_point(point(0.0, 0.0, 0.0).into()),
+            point(0.0, 0.0, 10.0).into()
+        ));
+        assert!(close(
+            dome.map_point(point(10.0, 0.0, 0.0).into()),
+            point(10.0, 0.0, 0.0).into()
+        ));
+        assert!(close(
+            dome.map_point(point(0.0, -10.0, 0.0).into()),
+            point(0.0, -10.0, 0.0).into()
+        ));
+
+        let sphere = SphereMapping {
+            coverage: Angle::PI,
+            ..dome
+        };
+        assert!(close(
+            sphere.map_point(point(10.0, 0.0, 0.0).into()),
+            point(0.0, 0.0, -10.0).into()
+        ));
+    }
+
+    #[test]
+    fn test_wrapped_circle_is_latitude() {
+        let dome = SphereMapping::dome(point(0.0, 0.0, 0.0), 10.0);
+        let circle = SweepArc::ellipse(
+            point(0.0, 0.0, 0.0),
+            Vector {
+                x: 5.0,
+                y: 5.0,
+                z: 0.0,
+            },
+        );
+        let latitude = dome.wrap(circle);
+        let height = (std::f64::consts::FRAC_PI_4 as Float).cos() * 10.0;
+        for i in 0..8 {
+            assert!((latitude.eval(i as Float / 8.0).z - height).abs() < 1e-4);
+        }
+
+        let radial = dome.wrap(LineSegment {
+            start: point(0.0, 0.0, 0.0),
+            end: point(10.0, 0.0, 0.0),
+        });
+        let quarter_meridian = std::f64::consts::FRAC_PI_2 as Float * 10.0;
+        assert!((radial.length() - quarter_meridian).abs() < 0.01);
+
+        let camera = Camera::new(
+            point(0.0, 0.0, 30.0),
+            point(0.0, 0.0, 0.0),
+            Angle::FRAC_PI_2,
+        );
+        assert_eq!(project(&latitude, &camera).len(), 1);
+    }
+}
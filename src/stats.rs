@@ -0,0 +1,75 @@
+use std::time::{Duration, Instant};
+
+use crate::{Float, Path, Vector, VectorValuedFn};
+
+/// counters collected while flattening paths for output
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct RenderStats {
+    pub paths: usize,
+    pub segments_flattened: usize,
+    /// straight lines in the flattened output
+    pub lines_emitted: usize,
+    pub elapsed: Duration,
+}
+
+/// flattens every path like the exporters do, within `tolerance`
+/// or with [`VectorValuedFn::sample_optimal`] when it's `None`,
+/// and reports what it took
+pub fn render_paths_with_stats(
+    paths: &[Path],
+    tolerance: Option<Float>,
+) -> (Vec<Vec<Vector>>, RenderStats) {
+    let started = Instant::now();
+    let mut stats = RenderStats::default();
+
+    let polylines: Vec<Vec<Vector>> = paths
+        .iter()
+        .map(|path| {
+            let points = match tolerance {
+                Some(tolerance) => path.flattened_with_tolerance(tolerance),
+                None => path.sample_optimal(),
+            };
+            stats.paths += 1;
+            stats.segments_flattened += path.segments().len();
+            stats.lines_emitted += points.len().saturating_sub(1);
+            points
+        })
+        .collect();
+
+    stats.elapsed = started.elapsed();
+    (polylines, stats)
+}
+
+#[cfg(test)]
+mod stats_tests {
+    use super::*;
+    use crate::test_util::vector;
+    use crate::{Point, SweepArc};
+
+    #[test]
+    fn test_render_stats() {
+        let paths = vec![
+            Path::rectangle(Point::from(vector(0.0, 0.0)), vector(1.0, 1.0)),
+            Path::new(vec![Box::new(SweepArc::ellipse(
+                vector(0.0, 0.0).into(),
+                vector(5.0, 5.0),
+            ))]),
+            Path::default(),
+        ];
+
+        let (polylines, stats) = render_paths_with_stats(&paths, Some(0.1));
+        assert_eq!(stats.paths, 3);
+        assert_eq!(stats.segments_flattened, 5);
+        assert_eq!(polylines[0].len(), 5);
+        assert_eq!(
+            stats.lines_emitted,
+            polylines
+                .iter()
+                .map(|p| p.len().saturating_sub(1))
+                .sum::<usize>()
+        );
+
+        let (_, coarse) = render_paths_with_stats(&paths, Some(1.0));
+        assert!(coarse.lines_emitted < stats.lines_emitted);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    flow_field::lattice, ring_contains, Angle, BBox, Float, Path, Point, Tolerance, VectorValuedFn,
+};
+
+/// candidates tried around a dot before it stops spawning new ones,
+/// and random starts missed in a row before the fill is considered done
+const CANDIDATES: usize = 30;
+
+/// blue noise dots filling the closed outline, no two closer than `min_distance`
+///
+/// Poisson-disk sampling after Bridson, the outline is flattened in the `xy` plane
+/// and insideness is even-odd, new regions are started from random points
+/// until they keep missing, the same seed always gives the same dots
+pub fn stipple(outline: &Path, min_distance: Float, seed: u32) -> Vec<Point> {
+    let mut ring: Vec<Point> = outline.samples_optimal().map(Point::from).collect();
+    if ring.len() > 1 && ring.first() == ring.last() {
+        ring.pop();
+    }
+    let Some(bounds) = outline.bounds() else {
+        return Vec::new();
+    };
+    if ring.len() < 3 || min_distance <= 0.0 {
+        return Vec::new();
+    }
+
+    let mut disks = Disks::new(bounds, min_distance);
+    let fits =
+        |disks: &Disks, p: Point| disks.is_free(p) && ring_contains(&ring, p, Tolerance::EXACT);
+    let mut draws = 0;
+    let mut random = || {
+        draws += 1;
+        lattice(draws, 0, seed)
+    };
+    let base = outline.start();
+    let at = |x: Float, y: Float| {
+        let mut p = base;
+        p.x = x;
+        p.y = y;
+        p
+    };
+
+    let mut misses = 0;
+    while misses < CANDIDATES {
+        let start = at(
+            bounds.min.x + random() * bounds.width(),
+            bounds.min.y + random() * bounds.height(),
+        );
+        if !fits(&disks, start) {
+            misses += 1;
+            continue;
+        }
+        misses = 0;
+        disks.insert(start);
+
+        let mut active = vec![start];
+        while !active.is_empty() {
+            let i = ((random() * active.len() as Float) as usize).min(active.len() - 1);
+            let from = active[i];
+            let found = (0..CANDIDATES).find_map(|_| {
+                let angle = Angle::from_radians(random() * Angle::TAU.to_radians());
+                let distance = min_distance * (1.0 + random());
+                let p = at(
+                    from.x + distance * angle.cos(),
+                    from.y + distance * angle.sin(),
+                );
+                fits(&disks, p).then_some(p)
+            });
+
+            match found {
+                Some(p) => {
+                    disks.insert(p);
+                    active.push(p);
+                }
+                None => {
+                    active.swap_remove(i);
+                }
+            }
+        }
+    }
+
+    disks.dots
+}
+
+/// background grid with at most one dot per cell for the distance checks
+struct Disks {
+    bounds: BBox,
+    min_distance: Float,
+    cell: Float,
+    cols: usize,
+    rows: usize,
+    grid: Vec<Option<usize>>,
+    dots: Vec<Point>,
+}
+
+impl Disks {
+    fn new(bounds: BBox, min_distance: Float) -> Self {
+        // a cell's diagonal is the distance, so no two dots share one
+        let cell = min_distance / (2.0 as Float).sqrt();
+        let cols = (bounds.width() / cell).ceil().max(1.0) as usize;
+        let rows = (bounds.height() / cell).ceil().max(1.0) as usize;
+        Self {
+            bounds,
+            min_distance,
+            cell,
+            cols,
+            rows,
+            grid: vec![None; cols * rows],
+            dots: Vec::new(),
+        }
+    }
+
+    fn cell_of(&self, p: Point) -> (usize, usize) {
+        let col = ((p.x - self.bounds.min.x) / self.cell) as usize;
+        let row = ((p.y - self.bounds.min.y) / self.cell) as usize;
+        (col.min(self.cols - 1), row.min(self.rows - 1))
+    }
+
+    /// within the bounds and at least the distance away from every dot
+    fn is_free(&self, p: Point) -> bool {
+        if !self.bounds.contains(p) {
+            return false;
+        }
+        let (col, row) = self.cell_of(p);
+        (row.saturating_sub(2)..(row + 3).min(self.rows))
+            .flat_map(|r| (col.saturating_sub(2)..(col + 3).min(self.cols)).map(move |c| (c, r)))
+            .filter_map(|(c, r)| self.grid[r * self.cols + c])
+            .all(|i| {
+                let dot = self.dots[i];
+                (dot.x - p.x).hypot(dot.y - p.y) >= self.min_distance
+            })
+    }
+
+    fn insert(&mut self, p: Point) {
+        let (col, row) = self.cell_of(p);
+        self.grid[row * self.cols + col] = Some(self.dots.len());
+        self.dots.push(p);
+    }
+}
+
+#[cfg(test)]
+mod stipple_tests {
+    use super::*;
+    use crate::test_util::point;
+
+    #[test]
+    fn test_stipple_circle() {
+        let circle = Path::circle(point(0.0, 0.0), 10.0);
+        let dots = stipple(&circle, 1.0, 7);
+
+        // at least half as dense as the 362 dots of a hexagonal packing
+        assert!(dots.len() > 181 && dots.len() <= 362, "{} dots", dots.len());
+        for (i, a) in dots.iter().enumerate() {
+            assert!(a.x.hypot(a.y) <= 10.0 + 1e-3);
+            for b in &dots[i + 1..] {
+                assert!((a.x - b.x).hypot(a.y - b.y) >= 1.0 - 1e-4);
+            }
+        }
+
+        assert_eq!(stipple(&circle, 1.0, 7), dots);
+        assert_ne!(stipple(&circle, 1.0, 8), dots);
+    }
+
+    #[test]
+    fn test_stipple_empty() {
+        let circle = Path::circle(point(0.0, 0.0), 10.0);
+        assert!(stipple(&circle, 0.0, 1).is_empty());
+        assert!(stipple(&Path::default(), 1.0, 1).is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{Float, GlVec, Path, Point, Polyline, VectorValuedFn};
+
+/// width of a stroke along its length
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WidthProfile {
+    Constant(Float),
+    /// changes linearly from `start` to `end`
+    Taper {
+        start: Float,
+        end: Float,
+    },
+    /// `(t, width)` stops interpolated linearly,
+    /// the first and last widths hold before and after the stops
+    Custom(Vec<(Float, Float)>),
+}
+
+impl WidthProfile {
+    /// custom profile from widths recorded at points, e.g. stylus pressure,
+    /// `t` of each stop is its distance along the points
+    ///
+    /// extra points or widths without a pair are ignored
+    pub fn from_samples(points: &[Point], widths: &[Float]) -> Self {
+        let mut distances = vec![0.0];
+        for w in points.windows(2) {
+            let d = GlVec::from(w[1]).distance(w[0].into());
+            distances.push(distances[distances.len() - 1] + d);
+        }
+        let total = distances[distances.len() - 1].max(Float::EPSILON);
+
+        Self::Custom(
+            distances
+                .iter()
+                .zip(widths)
+                .map(|(d, w)| (d / total, *w))
+                .collect(),
+        )
+    }
+
+    /// same widths from the other end
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::Constant(width) => Self::Constant(width),
+            Self::Taper { start, end } => Self::Taper {
+                start: end,
+                end: start,
+            },
+            Self::Custom(stops) => {
+                Self::Custom(stops.into_iter().rev().map(|(t, w)| (1.0 - t, w)).collect())
+            }
+        }
+    }
+
+    /// width at `t` from 0 to 1 along the stroke, never negative
+    pub fn width_at(&self, t: Float) -> Float {
+        let width = match self {
+            Self::Constant(width) => *width,
+            Self::Taper { start, end } => start + (end - start) * t.clamp(0.0, 1.0),
+            Self::Custom(stops) => {
+                let after = stops.iter().position(|(at, _)| *at > t);
+                match after {
+                    None => stops.last().map_or(0.0, |(_, w)| *w),
+                    Some(0) => stops[0].1,
+                    Some(i) => {
+                        let ((t0, w0), (t1, w1)) = (stops[i - 1], stops[i]);
+                        w0 + (w1 - w0) * (t - t0) / (t1 - t0)
+                    }
+                }
+            }
+        };
+        width.max(0.0)
+    }
+}
+
+/// closed outline of the flattened path stroked with the width profile
+/// in the `xy` plane, `t` of the profile follows the length of the path
+///
+/// the left side runs forward and the right side back,
+/// sharp turns narrower than the width may fold the outline
+pub fn stroke_to_path<F: VectorValuedFn + ?Sized>(path: &F, profile: &WidthProfile) -> Path {
+    let points: Vec<GlVec> = path.samples_optimal().map(GlVec::from).collect();
+    if points.len() < 2 {
+        return Path::default();
+    }
+
+    let mut distances = vec![0.0];
+    for w in points.windows(2) {
+        distances.push(distances[distances.len() - 1] + (w[1] - w[0]).length());
+    }
+    let total = distances[distances.len() - 1].max(Float::EPSILON);
+
+    let n = points.len();
+    let normal = |i: usize| {
+        let prev = points[i.saturating_sub(1)];
+        let next = points[(i + 1).min(n - 1)];
+        let mut normal = GlVec::ZERO;
+        normal.x = prev.y - next.y;
+        normal.y = next.x - prev.x;
+        normal.normalize_or_zero()
+    };
+
+    let (mut left, mut right) = (Vec::with_capacity(n), Vec::with_capacity(n));
+    for (i, (p, d)) in points.iter().zip(distances.iter()).enumerate() {
+        let offset = normal(i) * profile.width_at(d / total) / 2.0;
+        left.push(Point::from(*p + offset));
+        right.push(Point::from(*p - offset));
+    }
+
+    left.extend(right.into_iter().rev());
+    left.push(left[0]);
+    Polyline(left).into()
+}
+
+/// [`stroke_to_path`] of every path with its own width,
+/// or `default` for paths without one
+pub fn stroke_paths(paths: &[Path], default: &WidthProfile) -> Vec<Path> {
+    paths
+        .iter()
+        .map(|path| stroke_to_path(path, path.width.as_ref().unwrap_or(default)))
+        .collect()
+}
+
+#[cfg(test)]
+mod stroke_tests {
+    use super::*;
+    use crate::test_util::point;
+
+    #[test]
+    fn test_width_profiles() {
+        assert_eq!(WidthProfile::Constant(2.0).width_at(0.3), 2.0);
+
+        let taper = WidthProfile::Taper {
+            start: 4.0,
+            end: 0.0,
+        };
+        assert_eq!(taper.width_at(0.25), 3.0);
+        assert_eq!(taper.width_at(2.0), 0.0);
+
+        let petal = WidthProfile::Custom(vec![(0.2, 0.0), (0.5, 2.0), (1.0, 1.0)]);
+        assert_eq!(petal.width_at(0.0), 0.0);
+        assert!((petal.width_at(0.35) - 1.0).abs() < 1e-5);
+        assert_eq!(petal.width_at(0.75), 1.5);
+        assert_eq!(petal.width_at(1.5), 1.0);
+        assert_eq!(WidthProfile::Custom(vec![]).width_at(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_width_from_samples() {
+        let points = [point(0.0, 0.0), point(1.0, 0.0), point(4.0, 0.0)];
+        let profile = WidthProfile::from_samples(&points, &[1.0, 2.0, 0.5]);
+        assert_eq!(
+            profile,
+            WidthProfile::Custom(vec![(0.0, 1.0), (0.25, 2.0), (1.0, 0.5)])
+        );
+
+        let mut path = Path::from(Polyline(points.to_vec())).with_width(profile);
+        path.reverse();
+        assert_eq!(
+            path.width,
+            Some(WidthProfile::Custom(vec![
+                (0.0, 0.5),
+                (0.75, 2.0),
+                (1.0, 1.0)
+            ]))
+        );
+
+        let plain: Path = Polyline(points.to_vec()).into();
+        let stroked = stroke_paths(&[path, plain], &WidthProfile::Constant(1.0));
+        assert_eq!(stroked[1].area(), 4.0);
+        assert!(stroked[0].area() > stroked[1].area());
+    }
+
+    #[test]
+    fn test_stroke_to_path() {
+        let line = Polyline(vec![point(0.0, 0.0), point(10.0, 0.0)]);
+
+        let constant = stroke_to_path(&line, &WidthProfile::Constant(2.0));
+        assert_eq!(
+            constant.sample_optimal(),
+            [
+                point(0.0, 1.0),
+                point(10.0, 1.0),
+                point(10.0, -1.0),
+                point(0.0, -1.0),
+                point(0.0, 1.0)
+            ]
+            .map(crate::Vector::from)
+        );
+        assert_eq!(constant.area(), 20.0);
+
+        let tapered = stroke_to_path(
+            &line,
+            &WidthProfile::Taper {
+                start: 2.0,
+                end: 0.0,
+            },
+        );
+        assert_eq!(tapered.area(), 10.0);
+        assert_eq!(
+            stroke_to_path(&Polyline(vec![]), &WidthProfile::Constant(1.0))
+                .segments()
+                .len(),
+            0
+        );
+    }
+}
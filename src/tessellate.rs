@@ -0,0 +1,158 @@
+use crate::{
+    ring_signed_area, stroke_to_path, Float, GlVec, Path, Point, Polyline, Vector, VectorValuedFn,
+    WidthProfile,
+};
+
+/// triangle mesh, triangles index into `vertices` counter-clockwise when seen from outside,
+/// flat meshes face `+z`
+#[derive(Debug, Clone, Default)]
+pub struct Mesh {
+    pub vertices: Vec<Point>,
+    pub triangles: Vec<[usize; 3]>,
+}
+
+/// flat triangles filling every closed path flattened within `tolerance`,
+/// ready to upload as one vertex buffer, open paths are skipped
+///
+/// each outline is filled on its own so holes are covered,
+/// triangles face `+z`
+pub fn tessellate(paths: &[Path], tolerance: Float) -> Mesh {
+    let mut mesh = Mesh::default();
+    for path in paths {
+        fill(&mut mesh, path.flattened_with_tolerance(tolerance));
+    }
+    mesh
+}
+
+/// flat triangles covering the stroke outlines of the paths flattened within `tolerance`,
+/// each path is stroked with its own width or `default`, see [`stroke_to_path`]
+pub fn tessellate_strokes(paths: &[Path], tolerance: Float, default: &WidthProfile) -> Mesh {
+    let mut mesh = Mesh::default();
+    for path in paths {
+        let outline = stroke_to_path(
+            &Polyline::flatten(path, tolerance),
+            path.width.as_ref().unwrap_or(default),
+        );
+        fill(&mut mesh, outline.sample_optimal());
+    }
+    mesh
+}
+
+/// appends the triangulated outline when it's closed
+fn fill(mesh: &mut Mesh, outline: Vec<Vector>) {
+    let mut points: Vec<GlVec> = outline.into_iter().map(GlVec::from).collect();
+    let closed = points.len() > 3 && points[0].distance(points[points.len() - 1]) <= Float::EPSILON;
+    if !closed {
+        return;
+    }
+    points.pop();
+
+    let offset = mesh.vertices.len();
+    mesh.vertices.extend(points.iter().map(|p| Point::from(*p)));
+    mesh.triangles.extend(
+        triangulate(&points)
+            .into_iter()
+            .map(|t| t.map(|i| i + offset)),
+    );
+}
+
+/// ear clipping triangulation of a simple polygon in the xy plane,
+/// triangles are counter-clockwise
+pub(crate) fn triangulate(points: &[GlVec]) -> Vec<[usize; 3]> {
+    let mut remaining: Vec<usize> = (0..points.len()).collect();
+    if ring_signed_area(points.iter().map(|p| [p.x, p.y])) < 0.0 {
+        remaining.reverse();
+    }
+
+    let cross =
+        |a: GlVec, b: GlVec, c: GlVec| (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+
+    let mut triangles = Vec::new();
+    while remaining.len() > 3 {
+        let m = remaining.len();
+        let ear = (0..m).find(|&k| {
+            let (ia, ib, ic) = (
+                remaining[(k + m - 1) % m],
+                remaining[k],
+                remaining[(k + 1) % m],
+            );
+            let (a, b, c) = (points[ia], points[ib], points[ic]);
+            cross(a, b, c) > 0.0
+                && remaining.iter().all(|&i| {
+                    i == ia
+                        || i == ib
+                        || i == ic
+                        || !(cross(a, b, points[i]) >= 0.0
+                            && cross(b, c, points[i]) >= 0.0
+                            && cross(c, a, points[i]) >= 0.0)
+                })
+        });
+
+        // degenerate outlines have no proper ear left, clip anyway to terminate
+        let k = ear.unwrap_or(0);
+        triangles.push([
+            remaining[(k + m - 1) % m],
+            remaining[k],
+            remaining[(k + 1) % m],
+        ]);
+        remaining.remove(k);
+    }
+
+    if remaining.len() == 3 {
+        triangles.push([remaining[0], remaining[1], remaining[2]]);
+    }
+
+    triangles
+}
+
+#[cfg(test)]
+mod tessellate_tests {
+    use super::*;
+    use crate::test_util::{point, vector};
+    use crate::LineSegment;
+
+    /// area of a triangle projected on the `xy` plane, positive facing `+z`
+    fn flat_area(mesh: &Mesh, triangle: [usize; 3]) -> Float {
+        let [a, b, c] = triangle.map(|i| GlVec::from(mesh.vertices[i]));
+        let (ab, ac) = (b - a, c - a);
+        (ab.x * ac.y - ab.y * ac.x) / 2.0
+    }
+
+    #[test]
+    fn test_tessellate_fills() {
+        let size = vector(2.0, 3.0);
+        let mut reversed = Path::rectangle(point(5.0, 0.0), size);
+        reversed.reverse();
+        let open = Path::new(vec![Box::new(LineSegment {
+            start: point(0.0, 0.0),
+            end: point(1.0, 0.0),
+        })]);
+        let mesh = tessellate(
+            &[Path::rectangle(point(0.0, 0.0), size), reversed, open],
+            0.1,
+        );
+
+        assert_eq!(mesh.vertices.len(), 8);
+        assert_eq!(mesh.triangles.len(), 4);
+        let areas: Vec<Float> = mesh
+            .triangles
+            .iter()
+            .map(|t| flat_area(&mesh, *t))
+            .collect();
+        assert!((areas.iter().sum::<Float>() - 12.0).abs() < 1e-4);
+        assert!(areas.iter().all(|a| *a > 0.0));
+    }
+
+    #[test]
+    fn test_tessellate_strokes() {
+        let line = Path::new(vec![Box::new(LineSegment {
+            start: point(0.0, 0.0),
+            end: point(10.0, 0.0),
+        })]);
+        let mesh = tessellate_strokes(&[line], 0.1, &WidthProfile::Constant(1.0));
+
+        assert_eq!(mesh.triangles.len(), 2);
+        let area: Float = mesh.triangles.iter().map(|t| flat_area(&mesh, *t)).sum();
+        assert!((area - 10.0).abs() < 1e-4);
+    }
+}
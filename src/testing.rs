@@ -0,0 +1,191 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    Angle, BBox, CubicCurve, Float, GlVec, LineSegment, Path, PathSegment, Point, Polyline,
+    QuadraticCurve, SweepArc, Vector,
+};
+
+/// coordinates stay within this distance of the origin,
+/// large enough to be interesting and small enough to keep lengths finite
+const COORDINATE_RANGE: Float = 1000.0;
+
+/// most segments of an arbitrary path
+const MAX_SEGMENTS: usize = 16;
+
+/// finite float within `-range..=range`
+#[allow(clippy::unnecessary_cast)]
+pub fn arbitrary_float(u: &mut Unstructured, range: Float) -> Result<Float> {
+    let unit = u.int_in_range(0..=u32::MAX)? as f64 / u32::MAX as f64;
+    Ok(((unit * 2.0 - 1.0) * range as f64) as Float)
+}
+
+/// vector with every coordinate within the coordinate range
+pub fn arbitrary_vector(u: &mut Unstructured) -> Result<Vector> {
+    Ok(Vector {
+        x: arbitrary_float(u, COORDINATE_RANGE)?,
+        y: arbitrary_float(u, COORDINATE_RANGE)?,
+        #[cfg(feature = "3d")]
+        z: arbitrary_float(u, COORDINATE_RANGE)?,
+    })
+}
+
+/// point with every coordinate within the coordinate range
+pub fn arbitrary_point(u: &mut Unstructured) -> Result<Point> {
+    arbitrary_vector(u).map(Point::from)
+}
+
+/// one of the built-in segments
+pub fn arbitrary_segment(u: &mut Unstructured) -> Result<PathSegment> {
+    Ok(match u.int_in_range(0..=3)? {
+        0 => Box::new(LineSegment::arbitrary(u)?),
+        1 => Box::new(QuadraticCurve::arbitrary(u)?),
+        2 => Box::new(CubicCurve::arbitrary(u)?),
+        _ => Box::new(SweepArc::arbitrary(u)?),
+    })
+}
+
+impl<'a> Arbitrary<'a> for Angle {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let turn = Angle::TAU.to_radians();
+        Ok(Angle::from_radians(
+            (arbitrary_float(u, turn)? + turn) / 2.0,
+        ))
+    }
+}
+
+impl<'a> Arbitrary<'a> for LineSegment {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            start: arbitrary_point(u)?,
+            end: arbitrary_point(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for QuadraticCurve {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            start: arbitrary_point(u)?,
+            control: arbitrary_point(u)?,
+            end: arbitrary_point(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for CubicCurve {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            start: arbitrary_point(u)?,
+            control1: arbitrary_point(u)?,
+            control2: arbitrary_point(u)?,
+            end: arbitrary_point(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for SweepArc {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let radius = GlVec::from(arbitrary_vector(u)?).abs();
+        Ok(Self {
+            radius: radius.into(),
+            center: arbitrary_point(u)?,
+            start_angle: Angle::arbitrary(u)?,
+            sweep_angle: Angle::arbitrary(u)?,
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Polyline {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let n = u.int_in_range(2..=MAX_SEGMENTS + 1)?;
+        (0..n)
+            .map(|_| arbitrary_point(u))
+            .collect::<Result<Vec<_>>>()
+            .map(Self)
+    }
+}
+
+impl<'a> Arbitrary<'a> for BBox {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (a, b) = (arbitrary_vector(u)?, arbitrary_vector(u)?);
+        Ok(BBox::from_points([a, b]).unwrap())
+    }
+}
+
+/// continuous path of built-in segments, each starting where the previous ended
+impl<'a> Arbitrary<'a> for Path {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let n = u.int_in_range(1..=MAX_SEGMENTS)?;
+        let mut segments: Vec<PathSegment> = Vec::with_capacity(n);
+        for _ in 0..n {
+            let segment = arbitrary_segment(u)?;
+            let offset = match segments.last() {
+                Some(last) => GlVec::from(last.end()) - GlVec::from(segment.start()),
+                None => GlVec::ZERO,
+            };
+            segments.push(Box::new(crate::Translated {
+                offset: offset.into(),
+                source: segment,
+            }));
+        }
+        Ok(Path::new(segments))
+    }
+}
+
+#[cfg(test)]
+mod testing_tests {
+    use super::*;
+    use crate::{VectorValuedFn, VectorValuedFnExt};
+
+    /// deterministic pseudo random bytes to drive `Unstructured`
+    fn bytes(seed: u64, len: usize) -> Vec<u8> {
+        let mut state = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                state as u8
+            })
+            .collect()
+    }
+
+    fn close(a: Vector, b: Vector, tolerance: Float) -> bool {
+        (GlVec::from(a) - GlVec::from(b)).length() <= tolerance
+    }
+
+    #[test]
+    fn test_arbitrary_paths_are_continuous() {
+        for seed in 0..32 {
+            let data = bytes(seed, 1024);
+            let mut u = Unstructured::new(&data);
+            let path = Path::arbitrary(&mut u).unwrap();
+            for pair in path.segments().windows(2) {
+                assert!(close(pair[0].end().into(), pair[1].start().into(), 1e-2));
+            }
+            assert!(path.length().is_finite());
+        }
+    }
+
+    #[test]
+    fn test_transform_round_trip() {
+        for seed in 0..32 {
+            let data = bytes(seed, 256);
+            let mut u = Unstructured::new(&data);
+            let segment = CubicCurve::arbitrary(&mut u).unwrap();
+            let offset = arbitrary_vector(&mut u).unwrap();
+            let back: Vector = (-GlVec::from(offset)).into();
+            let angle = Angle::arbitrary(&mut u).unwrap();
+
+            let round_trip = segment
+                .translate(offset)
+                .rotate(angle)
+                .rotate(Angle::from_radians(-angle.to_radians()))
+                .translate(back);
+            for i in 0..=4 {
+                let t = i as Float / 4.0;
+                assert!(close(round_trip.eval(t), segment.eval(t), 0.05));
+            }
+        }
+    }
+}
@@ -0,0 +1,140 @@
+use pix::{chan::Ch32, el::Pixel, gray::Gray, Raster};
+
+use crate::{
+    contour::marching_squares, pick::distance_to_segment, Float, LineSegment, Path, PathSegment,
+    Point,
+};
+
+/// traces dark shapes of a bitmap into closed paths
+///
+/// pixels with luminance below `threshold` (between 0 and 1) are ink,
+/// outlines are found with marching squares and simplified
+/// to within half a pixel, coordinates are in pixels with `y` pointing down
+pub fn trace_bitmap<P>(raster: &Raster<P>, threshold: Float) -> Vec<Path>
+where
+    P: Pixel,
+    Ch32: From<P::Chan>,
+{
+    let width = raster.width() as usize + 2;
+    let height = raster.height() as usize + 2;
+    let mut darkness = vec![0.0; width * height];
+
+    for (y, row) in raster.rows(()).enumerate() {
+        for (x, p) in row.iter().enumerate() {
+            let gray: pix::gray::Gray32 = p.convert();
+            let luminance = f32::from(Gray::value(gray)) as Float;
+            darkness[(y + 1) * width + x + 1] = 1.0 - luminance;
+        }
+    }
+
+    marching_squares(&darkness, width, height, 1.0 - threshold)
+        .into_iter()
+        .map(|contour| {
+            let points: Vec<[Float; 2]> = contour
+                .into_iter()
+                .map(|[x, y]| [x - 0.5, y - 0.5])
+                .collect();
+            closed_path(&simplify(&points, 0.5))
+        })
+        .collect()
+}
+
+/// Ramer-Douglas-Peucker simplification of a polyline
+fn simplify(points: &[[Float; 2]], epsilon: Float) -> Vec<[Float; 2]> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    let (first, last) = (points[0], points[points.len() - 1]);
+    let (mut index, mut max_distance) = (0, 0.0);
+
+    for (i, &p) in points.iter().enumerate().take(points.len() - 1).skip(1) {
+        let d = distance_to_segment(p, first, last);
+        if d > max_distance {
+            index = i;
+            max_distance = d;
+        }
+    }
+
+    if max_distance > epsilon {
+        let mut left = simplify(&points[..=index], epsilon);
+        let right = simplify(&points[index..], epsilon);
+        left.pop();
+        left.extend(right);
+        left
+    } else {
+        vec![first, last]
+    }
+}
+
+fn closed_path(points: &[[Float; 2]]) -> Path {
+    let point = |[x, y]: [Float; 2]| Point {
+        x,
+        y,
+        #[cfg(feature = "3d")]
+        z: 0.0,
+    };
+
+    let mut points: Vec<Point> = points.iter().copied().map(point).collect();
+    if points.first() != points.last() {
+        points.push(points[0]);
+    }
+
+    Path::new(
+        points
+            .windows(2)
+            .map(|w| {
+                Box::new(LineSegment {
+                    start: w[0],
+                    end: w[1],
+                }) as PathSegment
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod trace_tests {
+    use pix::gray::SGray8;
+
+    use super::*;
+    use crate::VectorValuedFn;
+
+    #[test]
+    fn test_trace_square() {
+        let mut raster = Raster::<SGray8>::with_color(10, 10, SGray8::new(255));
+        raster.copy_color((2, 3, 4, 5), SGray8::new(0));
+
+        let paths = trace_bitmap(&raster, 0.5);
+
+        assert_eq!(paths.len(), 1);
+        let (min, max) = paths[0].sample_optimal().into_iter().fold(
+            ([Float::MAX; 2], [Float::MIN; 2]),
+            |(min, max), v| {
+                (
+                    [min[0].min(v.x), min[1].min(v.y)],
+                    [max[0].max(v.x), max[1].max(v.y)],
+                )
+            },
+        );
+        assert_eq!(min, [2.0, 3.0]);
+        assert_eq!(max, [6.0, 8.0]);
+        assert!(paths[0].segments().len() <= 8);
+    }
+
+    #[test]
+    fn test_trace_two_shapes() {
+        let mut raster = Raster::<SGray8>::with_color(12, 6, SGray8::new(255));
+        raster.copy_color((1, 1, 3, 3), SGray8::new(0));
+        raster.copy_color((7, 1, 3, 3), SGray8::new(0));
+
+        assert_eq!(trace_bitmap(&raster, 0.5).len(), 2);
+    }
+
+    #[test]
+    fn test_trace_blank() {
+        let raster = Raster::<SGray8>::with_color(4, 4, SGray8::new(255));
+
+        assert!(trace_bitmap(&raster, 0.5).is_empty());
+    }
+}
@@ -1,6 +1,6 @@
 use cfg_if::cfg_if;
 
-use crate::{Affine, GlVec, VectorValuedFn};
+use crate::{sampled_length, Affine, Angle, Float, GlVec, Primitive, Vector, VectorValuedFn};
 
 pub struct Transform<'v> {
     pub affine: Affine,
@@ -9,26 +9,397 @@ pub struct Transform<'v> {
 
 impl<'v> VectorValuedFn for Transform<'v> {
     fn eval(&self, t: crate::Float) -> crate::Vector {
-        let value = self.source.eval(t);
-        cfg_if! {
-            if #[cfg(feature = "3d")] {
-                self.affine.transform_point3(value.into()).into()
+        transform_point(&self.affine, self.source.eval(t))
+    }
+
+    fn length(&self) -> crate::Float {
+        sampled_length(self)
+    }
+}
+
+/// moves the source by `offset`
+#[derive(Debug, Clone, Copy)]
+pub struct Translated<F> {
+    pub offset: Vector,
+    pub source: F,
+}
+
+impl<F: VectorValuedFn> VectorValuedFn for Translated<F> {
+    fn eval(&self, t: Float) -> Vector {
+        (GlVec::from(self.source.eval(t)) + GlVec::from(self.offset)).into()
+    }
+
+    fn length(&self) -> Float {
+        self.source.length()
+    }
+
+    fn derivative(&self, t: Float) -> Vector {
+        self.source.derivative(t)
+    }
+
+    fn samples_adaptive(&self, tolerance: Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        map_points(self.source.samples_adaptive(tolerance), move |v| {
+            v + GlVec::from(self.offset)
+        })
+    }
+
+    fn primitive(&self) -> Option<Primitive> {
+        match self.source.primitive()? {
+            Primitive::Arc(mut arc) => {
+                arc.center = (GlVec::from(arc.center) + GlVec::from(self.offset)).into();
+                Some(Primitive::Arc(arc))
             }
-            else {
-                self.affine.transform_point2(value.into()).into()
+            p => p.transformed(&affine_translation(self.offset)),
+        }
+    }
+}
+
+/// rotates the source by `angle` around the origin,
+/// in 3d the rotation is around the `z` axis
+#[derive(Debug, Clone, Copy)]
+pub struct Rotated<F> {
+    pub angle: Angle,
+    pub source: F,
+}
+
+impl<F> Rotated<F> {
+    fn rotate(&self, v: GlVec) -> GlVec {
+        let (sin, cos) = (self.angle.sin(), self.angle.cos());
+        let mut r = v;
+        r.x = v.x * cos - v.y * sin;
+        r.y = v.x * sin + v.y * cos;
+        r
+    }
+}
+
+impl<F: VectorValuedFn> VectorValuedFn for Rotated<F> {
+    fn eval(&self, t: Float) -> Vector {
+        self.rotate(self.source.eval(t).into()).into()
+    }
+
+    fn length(&self) -> Float {
+        self.source.length()
+    }
+
+    fn derivative(&self, t: Float) -> Vector {
+        self.rotate(self.source.derivative(t).into()).into()
+    }
+
+    fn samples_adaptive(&self, tolerance: Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        map_points(self.source.samples_adaptive(tolerance), move |v| {
+            self.rotate(v)
+        })
+    }
+
+    fn primitive(&self) -> Option<Primitive> {
+        match self.source.primitive()? {
+            Primitive::Arc(mut arc) if arc.radius.x == arc.radius.y => {
+                arc.center = self.rotate(arc.center.into()).into();
+                arc.start_angle += self.angle;
+                Some(Primitive::Arc(arc))
+            }
+            p => p.transformed(&affine_rotation(self.angle)),
+        }
+    }
+}
+
+/// scales the source by `factor` per axis relative to the origin
+#[derive(Debug, Clone, Copy)]
+pub struct Scaled<F> {
+    pub factor: Vector,
+    pub source: F,
+}
+
+impl<F: VectorValuedFn> VectorValuedFn for Scaled<F> {
+    fn eval(&self, t: Float) -> Vector {
+        (GlVec::from(self.source.eval(t)) * GlVec::from(self.factor)).into()
+    }
+
+    fn length(&self) -> Float {
+        let factor = GlVec::from(self.factor).abs();
+        if factor.min_element() == factor.max_element() {
+            self.source.length() * factor.x
+        } else {
+            sampled_length(self)
+        }
+    }
+
+    fn derivative(&self, t: Float) -> Vector {
+        (GlVec::from(self.source.derivative(t)) * GlVec::from(self.factor)).into()
+    }
+
+    fn samples_adaptive(&self, tolerance: Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        let factor = GlVec::from(self.factor).abs().max_element();
+        if factor == 0.0 {
+            return map_points(self.source.samples_adaptive(tolerance), |_| GlVec::ZERO);
+        }
+        map_points(self.source.samples_adaptive(tolerance / factor), move |v| {
+            v * GlVec::from(self.factor)
+        })
+    }
+
+    fn primitive(&self) -> Option<Primitive> {
+        match self.source.primitive()? {
+            Primitive::Arc(mut arc) if self.factor.x > 0.0 && self.factor.y > 0.0 => {
+                arc.center = (GlVec::from(arc.center) * GlVec::from(self.factor)).into();
+                arc.radius = (GlVec::from(arc.radius) * GlVec::from(self.factor)).into();
+                Some(Primitive::Arc(arc))
             }
+            p => p.transformed(&affine_scale(self.factor)),
+        }
+    }
+}
+
+/// applies an arbitrary affine transformation to the source,
+/// the owning counterpart of [`Transform`]
+#[derive(Debug, Clone, Copy)]
+pub struct Transformed<F> {
+    pub affine: Affine,
+    pub source: F,
+}
+
+impl<F: VectorValuedFn> VectorValuedFn for Transformed<F> {
+    fn eval(&self, t: Float) -> Vector {
+        transform_point(&self.affine, self.source.eval(t))
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+
+    fn samples_adaptive(&self, tolerance: Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        Box::new(
+            self.source
+                .samples_adaptive(tolerance)
+                .map(move |v| transform_point(&self.affine, v)),
+        )
+    }
+
+    fn primitive(&self) -> Option<Primitive> {
+        self.source.primitive()?.transformed(&self.affine)
+    }
+}
+
+/// builder style adapters for every [`VectorValuedFn`]
+pub trait VectorValuedFnExt: VectorValuedFn + Sized {
+    /// moves the function by `offset`
+    fn translate(self, offset: Vector) -> Translated<Self> {
+        Translated {
+            offset,
+            source: self,
+        }
+    }
+
+    /// rotates the function around the origin
+    fn rotate(self, angle: Angle) -> Rotated<Self> {
+        Rotated {
+            angle,
+            source: self,
+        }
+    }
+
+    /// scales the function per axis relative to the origin
+    fn scale(self, factor: Vector) -> Scaled<Self> {
+        Scaled {
+            factor,
+            source: self,
+        }
+    }
+
+    /// applies an affine transformation to the function
+    fn transform(self, affine: Affine) -> Transformed<Self> {
+        Transformed {
+            affine,
+            source: self,
+        }
+    }
+
+    /// traverses the function in the opposite direction
+    fn reversed(self) -> Reversed<Self> {
+        Reversed { source: self }
+    }
+}
+
+impl<F: VectorValuedFn> VectorValuedFnExt for F {}
+
+/// applies an affine transformation to a point
+pub(crate) fn transform_point(affine: &Affine, value: Vector) -> Vector {
+    cfg_if! {
+        if #[cfg(feature = "3d")] {
+            affine.transform_point3(value.into()).into()
         }
+        else {
+            affine.transform_point2(value.into()).into()
+        }
+    }
+}
+
+pub(crate) fn affine_translation(offset: Vector) -> Affine {
+    Affine::from_translation(offset.into())
+}
+
+pub(crate) fn affine_rotation(angle: Angle) -> Affine {
+    cfg_if! {
+        if #[cfg(feature = "3d")] {
+            Affine::from_rotation_z(angle.to_radians())
+        }
+        else {
+            Affine::from_angle(angle.to_radians())
+        }
+    }
+}
+
+pub(crate) fn affine_scale(factor: Vector) -> Affine {
+    Affine::from_scale(factor.into())
+}
+
+fn map_points<'p>(
+    points: Box<dyn Iterator<Item = Vector> + 'p>,
+    f: impl Fn(GlVec) -> GlVec + 'p,
+) -> Box<dyn Iterator<Item = Vector> + 'p> {
+    Box::new(points.map(move |v| f(v.into()).into()))
+}
+
+/// traverses the source in the opposite direction
+#[derive(Debug, Clone, Copy)]
+pub struct Reversed<F> {
+    pub source: F,
+}
+
+impl<F: VectorValuedFn> VectorValuedFn for Reversed<F> {
+    fn eval(&self, t: crate::Float) -> crate::Vector {
+        self.source.eval(1.0 - t)
+    }
+
+    fn length(&self) -> crate::Float {
+        self.source.length()
+    }
+
+    fn samples_optimal(&self) -> Box<dyn Iterator<Item = crate::Vector> + '_> {
+        Box::new(self.source.sample_optimal().into_iter().rev())
+    }
+
+    fn samples_adaptive(
+        &self,
+        tolerance: crate::Float,
+    ) -> Box<dyn Iterator<Item = crate::Vector> + '_> {
+        Box::new(self.source.sample_adaptive(tolerance).into_iter().rev())
+    }
+
+    fn primitive(&self) -> Option<crate::Primitive> {
+        self.source.primitive().and_then(|p| p.reversed())
+    }
+}
+
+impl<F: VectorValuedFn + ?Sized> VectorValuedFn for Box<F> {
+    fn eval(&self, t: crate::Float) -> crate::Vector {
+        self.as_ref().eval(t)
     }
 
     fn length(&self) -> crate::Float {
-        let mut samples = self.sample_evenly(1000).into_iter().map(GlVec::from);
-        let mut length = 0.0;
-        let mut prev = samples.next().unwrap();
+        self.as_ref().length()
+    }
+
+    fn samples(&self, num_samples: usize) -> Box<dyn Iterator<Item = crate::Vector> + '_> {
+        self.as_ref().samples(num_samples)
+    }
+
+    fn samples_optimal(&self) -> Box<dyn Iterator<Item = crate::Vector> + '_> {
+        self.as_ref().samples_optimal()
+    }
+
+    fn samples_adaptive(
+        &self,
+        tolerance: crate::Float,
+    ) -> Box<dyn Iterator<Item = crate::Vector> + '_> {
+        self.as_ref().samples_adaptive(tolerance)
+    }
+
+    fn second_derivative(&self, t: crate::Float) -> crate::Vector {
+        self.as_ref().second_derivative(t)
+    }
+
+    fn derivative(&self, t: crate::Float) -> crate::Vector {
+        self.as_ref().derivative(t)
+    }
+
+    fn normal(&self, t: crate::Float) -> crate::Vector {
+        self.as_ref().normal(t)
+    }
+
+    fn primitive(&self) -> Option<crate::Primitive> {
+        self.as_ref().primitive()
+    }
+}
+
+#[cfg(test)]
+mod transform_tests {
+    use super::*;
+    use crate::test_util::vector;
+    use crate::{LineSegment, Point, SweepArc};
 
-        for point in samples {
-            length += (point - prev).length();
-            prev = point;
+    fn assert_close(a: Vector, b: Vector) {
+        let d = GlVec::from(a) - GlVec::from(b);
+        assert!(d.length() < 1e-3, "{a:?} != {b:?}");
+    }
+
+    fn line() -> LineSegment {
+        LineSegment {
+            start: Point::from(vector(1.0, 0.0)),
+            end: Point::from(vector(2.0, 0.0)),
+        }
+    }
+
+    #[test]
+    fn test_translate_rotate_scale() {
+        let moved = line().translate(vector(0.0, 1.0));
+        assert_close(moved.eval(0.0), vector(1.0, 1.0));
+        assert_eq!(moved.length(), 1.0);
+
+        let rotated = line().rotate(Angle::FRAC_PI_2);
+        assert_close(rotated.eval(1.0), vector(0.0, 2.0));
+        assert_close(rotated.derivative(0.5), vector(0.0, 1.0));
+
+        let scaled = line().scale(vector(2.0, 2.0));
+        assert_close(scaled.eval(1.0), vector(4.0, 0.0));
+        assert_eq!(scaled.length(), 2.0);
+
+        let chained = line()
+            .scale(vector(2.0, 2.0))
+            .rotate(Angle::PI)
+            .translate(vector(1.0, 1.0));
+        assert_close(chained.eval(0.0), vector(-1.0, 1.0));
+        assert_close(chained.eval(1.0), vector(-3.0, 1.0));
+    }
+
+    #[test]
+    fn test_transform_affine() {
+        let affine = Affine::from_translation(vector(3.0, 4.0).into());
+        let transformed = line().transform(affine);
+
+        assert_close(transformed.eval(0.5), vector(4.5, 4.0));
+        assert!((transformed.length() - 1.0).abs() < 1e-4);
+        assert_eq!(transformed.sample_optimal().len(), 2);
+    }
+
+    #[test]
+    fn test_primitives_preserved() {
+        let arc = SweepArc::ellipse(Point::from(vector(1.0, 0.0)), vector(2.0, 2.0));
+
+        match arc.rotate(Angle::FRAC_PI_2).primitive() {
+            Some(Primitive::Arc(a)) => {
+                assert_close(a.center.into(), vector(0.0, 1.0));
+                assert_eq!(a.start_angle, Angle::FRAC_PI_2);
+            }
+            p => panic!("{p:?}"),
+        }
+
+        match line().translate(vector(0.0, 1.0)).reversed().primitive() {
+            Some(Primitive::Line(l)) => {
+                assert_close(l.start.into(), vector(2.0, 1.0));
+                assert_close(l.end.into(), vector(1.0, 1.0));
+            }
+            p => panic!("{p:?}"),
         }
-        length
     }
 }
@@ -2,7 +2,7 @@ use std::ops::Range;
 
 use cfg_if::cfg_if;
 
-use crate::{Float, GlVec, Point, Vector};
+use crate::{Float, GlVec, Point, Primitive, Vector};
 
 /// the heart and soul of the `mandala`
 ///
@@ -50,55 +50,105 @@ pub trait VectorValuedFn {
         self.sample_range(0.0..1.0, num_samples)
     }
 
-    /// Sample the function evenly
-    /// from 0 to 1 with optimal increment of `t`,
-    /// optimizes the increment for every next step
+    /// lazily evaluates `num_samples` points evenly from 0 to 1,
+    /// the streaming counterpart of [`VectorValuedFn::sample_evenly`]
+    fn samples(&self, num_samples: usize) -> Box<dyn Iterator<Item = Vector> + '_> {
+        let last = num_samples.saturating_sub(1).max(1) as Float;
+        Box::new((0..num_samples).map(move |i| self.eval(i as Float / last)))
+    }
+
+    /// Sample the function from 0 to 1 with as few points as needed
+    /// to follow it closely, straight parts get few points and tight bends many
     ///
-    /// the default implementation is "universal" but does't promise the best performance
+    /// collects [`VectorValuedFn::samples_optimal`]
     fn sample_optimal(&self) -> Vec<Vector> {
-        let mut points = Vec::new();
-
-        if self.length() == 0.0 {
-            return points;
-        }
-
-        let mut t = 0.0;
-        let mut increment;
-
-        let start_sample: GlVec = self.eval(0.0).into();
-        let mid_sample: GlVec = self.eval(0.5).into();
-        let end_sample: GlVec = self.eval(1.0).into();
+        self.samples_optimal().collect()
+    }
 
-        let start_to_mid = mid_sample - start_sample;
-        let mid_to_end = end_sample - mid_sample;
+    /// lazily yields the points of [`VectorValuedFn::sample_optimal`]
+    ///
+    /// the default implementation uses [`VectorValuedFn::samples_adaptive`]
+    /// with a tolerance relative to the length of the function
+    fn samples_optimal(&self) -> Box<dyn Iterator<Item = Vector> + '_> {
+        self.samples_adaptive(self.length() * OPTIMAL_RELATIVE_TOLERANCE)
+    }
 
-        let start_to_mid_length = magnitude(start_to_mid);
-        let mid_to_end_length = magnitude(mid_to_end);
+    /// Sample the function by recursively splitting the range of `t`
+    /// until the chord of every part is within `tolerance` of the function
+    ///
+    /// collects [`VectorValuedFn::samples_adaptive`]
+    fn sample_adaptive(&self, tolerance: Float) -> Vec<Vector> {
+        self.samples_adaptive(tolerance).collect()
+    }
 
-        let tolerance = (start_to_mid_length + mid_to_end_length) * Float::EPSILON;
+    /// lazily yields the points of [`VectorValuedFn::sample_adaptive`],
+    /// only the pending ranges are kept in memory
+    fn samples_adaptive(&self, tolerance: Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        if self.length() == 0.0 {
+            return Box::new(std::iter::empty());
+        }
 
-        while t < 1.0 {
-            let derivative: GlVec = self.derivative(t).into();
-            let length = magnitude(derivative);
+        let start = self.eval(0.0);
+        Box::new(AdaptiveSamples {
+            f: self,
+            tolerance,
+            start: Some(start),
+            pending: vec![Span {
+                t0: 0.0,
+                p0: start.into(),
+                t1: 1.0,
+                p1: self.eval(1.0).into(),
+                depth: 0,
+            }],
+        })
+    }
 
-            if length > tolerance {
-                increment =
-                    (0.1 / length).clamp(Float::EPSILON.powi(2), (1.0 - t).max(Float::EPSILON));
-            } else {
-                increment = tolerance;
-            }
+    /// Flatten the function for display at `pixels_per_unit` scale,
+    /// the flattening tolerance is a quarter of a pixel
+    /// so zoomed out views get fewer points and zoomed in views more
+    fn flattened_with_scale(&self, pixels_per_unit: Float) -> Vec<Vector> {
+        self.flattened_with_tolerance(0.25 / pixels_per_unit)
+    }
 
-            points.push(self.eval(t));
-            t += increment;
+    /// Flatten the function into points such that the lines between them
+    /// deviate from the function by at most `tolerance`
+    fn flattened_with_tolerance(&self, tolerance: Float) -> Vec<Vector> {
+        self.sample_adaptive(tolerance)
+    }
 
-            if t > 1.0 {
-                t = 1.0;
-                points.push(self.eval(t));
-                break;
-            }
+    /// parameters `t` at each of the `distances` measured along the function
+    ///
+    /// inverts a table of cumulative lengths between evenly spaced samples,
+    /// scaled to [`VectorValuedFn::length`], distances past either end are clamped
+    fn params_at_lengths(&self, distances: &[Float]) -> Vec<Float> {
+        let mut samples = self.samples(ARC_LENGTH_SAMPLES + 1).map(GlVec::from);
+        let mut prev = samples.next().unwrap_or_default();
+        let mut table = vec![0.0];
+        for point in samples {
+            table.push(table[table.len() - 1] + (point - prev).length());
+            prev = point;
         }
 
-        points
+        let (total, length) = (table[ARC_LENGTH_SAMPLES], self.length());
+        if total <= 0.0 || length <= 0.0 {
+            return vec![0.0; distances.len()];
+        }
+        distances
+            .iter()
+            .map(|d| {
+                let target = (d / length).clamp(0.0, 1.0) * total;
+                let i = table
+                    .partition_point(|s| *s < target)
+                    .clamp(1, ARC_LENGTH_SAMPLES);
+                let span = table[i] - table[i - 1];
+                let within = if span > 0.0 {
+                    (target - table[i - 1]) / span
+                } else {
+                    0.0
+                };
+                (i as Float - 1.0 + within) / ARC_LENGTH_SAMPLES as Float
+            })
+            .collect()
     }
 
     /// Compute the derivative of the function,
@@ -114,6 +164,38 @@ pub trait VectorValuedFn {
         (d / (2.0 * h)).into()
     }
 
+    /// Compute the second derivative of the function
+    fn second_derivative(&self, t: Float) -> Vector {
+        let h = Float::EPSILON.powf(0.25);
+        let p1: GlVec = self.eval(t + h).into();
+        let p0: GlVec = self.eval(t).into();
+        let p2: GlVec = self.eval(t - h).into();
+
+        ((p1 - 2.0 * p0 + p2) / (h * h)).into()
+    }
+
+    /// Compute the curvature at a given `t` value,
+    /// the inverse of the radius of the osculating circle
+    fn curvature(&self, t: Float) -> Float {
+        let d: GlVec = self.derivative(t).into();
+        let dd: GlVec = self.second_derivative(t).into();
+        let speed = magnitude(d);
+
+        if speed <= Float::EPSILON {
+            return 0.0;
+        }
+
+        cfg_if! {
+            if #[cfg(feature = "3d")] {
+                let cross = magnitude(d.cross(dd));
+            } else {
+                let cross = d.perp_dot(dd).abs();
+            }
+        }
+
+        cross / speed.powi(3)
+    }
+
     /// Compute the normal vector at a given `t` value.
     fn normal(&self, t: Float) -> Vector {
         let d: GlVec = self.derivative(t).into();
@@ -127,6 +209,276 @@ pub trait VectorValuedFn {
             None => GlVec::default().into(),
         }
     }
+
+    /// exact geometry of the function if it's one of the built-in segments,
+    /// used by exporters to preserve lines, arcs and curves
+    fn primitive(&self) -> Option<Primitive> {
+        None
+    }
+}
+
+/// concatenates functions giving each an equal share of `t`,
+/// unlike [`crate::Path`] which shares `t` by length
+pub struct Chain(pub Vec<Box<dyn VectorValuedFn>>);
+
+impl VectorValuedFn for Chain {
+    fn eval(&self, t: Float) -> Vector {
+        let n = self.0.len();
+        let scaled = t.clamp(0.0, 1.0) * n as Float;
+        let i = (scaled as usize).min(n - 1);
+        self.0[i].eval(scaled - i as Float)
+    }
+
+    fn length(&self) -> Float {
+        self.0.iter().map(|f| f.length()).sum()
+    }
+
+    fn samples_adaptive(&self, tolerance: Float) -> Box<dyn Iterator<Item = Vector> + '_> {
+        Box::new(dedup_points(
+            self.0
+                .iter()
+                .flat_map(move |f| f.samples_adaptive(tolerance)),
+        ))
+    }
+}
+
+/// interpolates between `a` and `b`,
+/// `t_fn` gives the weight of `b` for every `t`
+#[derive(Clone)]
+pub struct Blend<A, B, W> {
+    pub a: A,
+    pub b: B,
+    pub t_fn: W,
+}
+
+impl<A, B, W> VectorValuedFn for Blend<A, B, W>
+where
+    A: VectorValuedFn,
+    B: VectorValuedFn,
+    W: Fn(Float) -> Float,
+{
+    fn eval(&self, t: Float) -> Vector {
+        let a: GlVec = self.a.eval(t).into();
+        let b: GlVec = self.b.eval(t).into();
+        a.lerp(b, (self.t_fn)(t)).into()
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+/// displaces `base` by `displacement`
+///
+/// with `along_normal` the displacement is in the local frame of `base`:
+/// `x` along the tangent, `y` along [`VectorValuedFn::normal`]
+/// and in 3d `z` along their cross product,
+/// otherwise it's added as is
+#[derive(Clone)]
+pub struct Modulate<F, D> {
+    pub base: F,
+    pub displacement: D,
+    pub along_normal: bool,
+}
+
+impl<F, D> VectorValuedFn for Modulate<F, D>
+where
+    F: VectorValuedFn,
+    D: Fn(Float) -> Vector,
+{
+    fn eval(&self, t: Float) -> Vector {
+        let base: GlVec = self.base.eval(t).into();
+        let d: GlVec = (self.displacement)(t).into();
+
+        if !self.along_normal {
+            return (base + d).into();
+        }
+
+        let tangent = GlVec::from(self.base.derivative(t)).normalize_or_zero();
+        let normal = GlVec::from(self.base.normal(t));
+
+        cfg_if! {
+            if #[cfg(feature = "3d")] {
+                let offset = tangent * d.x + normal * d.y + tangent.cross(normal) * d.z;
+            } else {
+                let offset = tangent * d.x + normal * d.y;
+            }
+        }
+
+        (base + offset).into()
+    }
+
+    fn length(&self) -> Float {
+        sampled_length(self)
+    }
+}
+
+/// tolerance of [`VectorValuedFn::sample_optimal`] as a fraction of the length
+const OPTIMAL_RELATIVE_TOLERANCE: Float = 1e-4;
+
+/// every range is split at least this many times,
+/// so closed or symmetric functions aren't mistaken for straight lines
+const MIN_SUBDIVISION_DEPTH: u32 = 3;
+
+/// ranges are never split more than this many times
+const MAX_SUBDIVISION_DEPTH: u32 = 16;
+
+/// range of `t` waiting to be checked for flatness
+struct Span {
+    t0: Float,
+    p0: GlVec,
+    t1: Float,
+    p1: GlVec,
+    depth: u32,
+}
+
+/// depth first subdivision yielding the end of every flat range,
+/// preceded by the start of the function
+struct AdaptiveSamples<'f, F: ?Sized> {
+    f: &'f F,
+    tolerance: Float,
+    start: Option<Vector>,
+    pending: Vec<Span>,
+}
+
+impl<F: VectorValuedFn + ?Sized> Iterator for AdaptiveSamples<'_, F> {
+    type Item = Vector;
+
+    fn next(&mut self) -> Option<Vector> {
+        if let Some(start) = self.start.take() {
+            return Some(start);
+        }
+
+        while let Some(Span {
+            t0,
+            p0,
+            t1,
+            p1,
+            depth,
+        }) = self.pending.pop()
+        {
+            let tm = (t0 + t1) / 2.0;
+            let pm: GlVec = self.f.eval(tm).into();
+
+            let flat = depth >= MIN_SUBDIVISION_DEPTH
+                && [0.25, 0.5, 0.75].into_iter().all(|q| {
+                    let p = if q == 0.5 {
+                        pm
+                    } else {
+                        self.f.eval(t0 + (t1 - t0) * q).into()
+                    };
+                    distance_to_chord(p, p0, p1) <= self.tolerance
+                });
+
+            if flat || depth >= MAX_SUBDIVISION_DEPTH {
+                return Some(p1.into());
+            }
+
+            self.pending.push(Span {
+                t0: tm,
+                p0: pm,
+                t1,
+                p1,
+                depth: depth + 1,
+            });
+            self.pending.push(Span {
+                t0,
+                p0,
+                t1: tm,
+                p1: pm,
+                depth: depth + 1,
+            });
+        }
+
+        None
+    }
+}
+
+/// spans of the cumulative length table behind [`VectorValuedFn::params_at_lengths`]
+const ARC_LENGTH_SAMPLES: usize = 1000;
+
+/// skips points equal to the one before, like [`Vec::dedup`]
+pub(crate) fn dedup_points<'i>(
+    points: impl Iterator<Item = Vector> + 'i,
+) -> impl Iterator<Item = Vector> + 'i {
+    let mut last = None;
+    points.filter(move |p| {
+        let keep = last.as_ref() != Some(p);
+        last = Some(*p);
+        keep
+    })
+}
+
+/// distance from `p` to the closest point of the `a`-`b` line segment
+fn distance_to_chord(p: GlVec, a: GlVec, b: GlVec) -> Float {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    let t = if len_sq == 0.0 {
+        0.0
+    } else {
+        ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0)
+    };
+    magnitude(p - (a + ab * t))
+}
+
+/// nodes and weights of the 5 point Gauss-Legendre rule on `-1..=1`
+const GAUSS_LEGENDRE: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_08),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_08),
+];
+
+/// deepest halving of an interval before its estimate is accepted as is
+const MAX_QUADRATURE_DEPTH: usize = 16;
+
+/// arc length integrating the speed `|f'(t)|` with adaptive Gauss-Legendre quadrature
+///
+/// intervals are halved until their halves add up to within `tolerance`
+/// of the whole, relative to the estimate, or within the rounding noise
+/// of a central difference [`VectorValuedFn::derivative`], `sqrt(EPSILON)`
+/// of the interval's share of the total
+pub fn quadrature_length<F: VectorValuedFn + ?Sized>(f: &F, tolerance: Float) -> Float {
+    let estimate = |a: Float, b: Float| {
+        let (middle, half) = ((a + b) / 2.0, (b - a) / 2.0);
+        GAUSS_LEGENDRE
+            .iter()
+            .map(|(x, w)| *w as Float * magnitude(f.derivative(middle + half * *x as Float).into()))
+            .sum::<Float>()
+            * half
+    };
+
+    let tolerance = tolerance.abs().max(Float::EPSILON);
+    let whole = estimate(0.0, 1.0);
+    let noise = Float::EPSILON.sqrt() * whole.abs();
+    let mut stack = vec![(0.0, 1.0, whole, 0)];
+    let mut length = 0.0;
+    while let Some((a, b, whole, depth)) = stack.pop() {
+        let middle = (a + b) / 2.0;
+        let (left, right) = (estimate(a, middle), estimate(middle, b));
+        let allowed = tolerance * whole.abs() + noise * (b - a);
+        if depth >= MAX_QUADRATURE_DEPTH || (left + right - whole).abs() <= allowed {
+            length += left + right;
+        } else {
+            stack.push((a, middle, left, depth + 1));
+            stack.push((middle, b, right, depth + 1));
+        }
+    }
+    length
+}
+
+/// length of the polyline through a thousand evenly spaced samples
+pub(crate) fn sampled_length<F: VectorValuedFn + ?Sized>(f: &F) -> Float {
+    let mut samples = f.sample_evenly(1000).into_iter().map(GlVec::from);
+    let mut length = 0.0;
+    let mut prev = samples.next().unwrap();
+
+    for point in samples {
+        length += (point - prev).length();
+        prev = point;
+    }
+    length
 }
 
 #[allow(dead_code)]
@@ -140,3 +492,157 @@ pub(crate) fn magnitude(d: GlVec) -> Float {
         }
     }
 }
+
+#[cfg(test)]
+mod vector_valued_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{CubicCurve, SweepArc, VectorValuedFnExt};
+
+    #[test]
+    fn test_quadrature_length() {
+        let curve = CubicCurve {
+            start: GlVec::ZERO.into(),
+            control1: GlVec::X.into(),
+            control2: (GlVec::X * 2.0).into(),
+            end: (GlVec::X * 3.0).into(),
+        };
+        assert!((quadrature_length(&curve, 1e-6) - 3.0).abs() < 1e-3);
+
+        let coarse = quadrature_length(&curve, 0.5);
+        assert!((coarse - 3.0).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_sample_adaptive_straight() {
+        let straight = CubicCurve {
+            start: point(0.0, 0.0),
+            control1: point(1.0, 0.0),
+            control2: point(2.0, 0.0),
+            end: point(3.0, 0.0),
+        };
+
+        assert_eq!(
+            straight.sample_adaptive(0.01).len(),
+            2usize.pow(MIN_SUBDIVISION_DEPTH) + 1
+        );
+    }
+
+    #[test]
+    fn test_samples_streaming() {
+        let curve = CubicCurve {
+            start: point(0.0, 0.0),
+            control1: point(1.0, 2.0),
+            control2: point(2.0, -2.0),
+            end: point(3.0, 0.0),
+        };
+
+        assert_eq!(curve.samples(7).collect::<Vec<_>>(), curve.sample_evenly(7));
+        assert_eq!(
+            curve.samples_adaptive(0.01).collect::<Vec<_>>(),
+            curve.sample_adaptive(0.01)
+        );
+
+        let path = crate::Path::new(vec![Box::new(curve), Box::new(curve.reversed())]);
+        let streamed: Vec<Vector> = path.samples_optimal().collect();
+        assert_eq!(streamed, path.sample_optimal());
+        assert!(streamed.windows(2).all(|w| w[0] != w[1]));
+        assert_eq!(path.samples_adaptive(0.01).take(3).count(), 3);
+    }
+
+    #[test]
+    fn test_sample_adaptive_within_tolerance() {
+        let arc = SweepArc {
+            radius: Vector::from(point(10.0, 10.0)),
+            center: point(0.0, 0.0),
+            start_angle: crate::Angle::ZERO,
+            sweep_angle: crate::Angle::PI,
+        };
+
+        let coarse = arc.sample_adaptive(0.1);
+        let fine = arc.sample_adaptive(0.001);
+        assert!(fine.len() > coarse.len());
+
+        for w in fine.windows(2) {
+            let mid = (GlVec::from(w[0]) + GlVec::from(w[1])) / 2.0;
+            assert!(10.0 - magnitude(mid) <= 0.001);
+        }
+        assert_eq!(fine.first(), Some(&arc.eval(0.0)));
+        assert_eq!(fine.last(), Some(&arc.eval(1.0)));
+    }
+
+    #[test]
+    fn test_curvature() {
+        let arc = SweepArc::ellipse(point(0.0, 0.0), Vector::from(point(4.0, 4.0)));
+
+        assert!((arc.curvature(0.3) - 0.25).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_chain() {
+        let chain = Chain(vec![
+            Box::new(crate::LineSegment {
+                start: point(0.0, 0.0),
+                end: point(1.0, 0.0),
+            }),
+            Box::new(crate::LineSegment {
+                start: point(1.0, 0.0),
+                end: point(1.0, 3.0),
+            }),
+        ]);
+
+        assert_eq!(chain.eval(0.25), Vector::from(point(0.5, 0.0)));
+        assert_eq!(chain.eval(0.75), Vector::from(point(1.0, 1.5)));
+        assert_eq!(chain.eval(1.0), Vector::from(point(1.0, 3.0)));
+        assert_eq!(chain.length(), 4.0);
+        assert_eq!(chain.sample_optimal().len(), 3);
+    }
+
+    #[test]
+    fn test_blend() {
+        let blend = Blend {
+            a: crate::LineSegment {
+                start: point(0.0, 0.0),
+                end: point(1.0, 0.0),
+            },
+            b: crate::LineSegment {
+                start: point(0.0, 2.0),
+                end: point(1.0, 2.0),
+            },
+            t_fn: |t: Float| t,
+        };
+
+        assert_eq!(blend.eval(0.0), Vector::from(point(0.0, 0.0)));
+        assert_eq!(blend.eval(0.5), Vector::from(point(0.5, 1.0)));
+        assert_eq!(blend.eval(1.0), Vector::from(point(1.0, 2.0)));
+    }
+
+    #[test]
+    fn test_modulate_along_normal() {
+        let circle = SweepArc::ellipse(point(0.0, 0.0), Vector::from(point(10.0, 10.0)));
+        let petals = Modulate {
+            base: circle,
+            displacement: |t: Float| {
+                Vector::from(point(0.0, (t * crate::Angle::TAU.to_radians() * 6.0).sin()))
+            },
+            along_normal: true,
+        };
+
+        for i in 0..24 {
+            let t = i as Float / 24.0;
+            let wave = (t * crate::Angle::TAU.to_radians() * 6.0).sin();
+            let offset = GlVec::from(petals.eval(t)) - GlVec::from(circle.eval(t));
+            assert!((magnitude(offset) - wave.abs()).abs() < 1e-2);
+
+            #[cfg(feature = "2d")]
+            assert!((magnitude(petals.eval(t).into()) - (10.0 - wave)).abs() < 1e-2);
+        }
+
+        let shifted = Modulate {
+            base: circle,
+            displacement: |_: Float| Vector::from(point(1.0, 0.0)),
+            along_normal: false,
+        };
+        assert_eq!(shifted.eval(0.0), Vector::from(point(11.0, 0.0)));
+    }
+}
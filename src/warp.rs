@@ -0,0 +1,82 @@
+use crate::{Float, GlVec, Path, Point, Polyline, VectorValuedFn};
+
+/// longest straight step in the unit square before it's split,
+/// so straight lines bend with the band
+const MAX_STEP: Float = 1.0 / 64.0;
+
+/// drawing in the unit square bent into the band between two outlines
+///
+/// `x` runs along both outlines by length from their starts,
+/// `y` runs from `inner` at `0` to `outer` at `1`,
+/// the outlines should run in the same direction from matching starts
+///
+/// paths come back flattened, the metadata is kept
+pub fn warp_between(inner: &Path, outer: &Path, drawing: &[Path]) -> Vec<Path> {
+    if inner.segments().is_empty() || outer.segments().is_empty() {
+        return Vec::new();
+    }
+
+    let warp = |p: GlVec| {
+        let u = p.x.clamp(0.0, 1.0);
+        let (near, far) = (GlVec::from(inner.eval(u)), GlVec::from(outer.eval(u)));
+        Point::from(near.lerp(far, p.y))
+    };
+
+    drawing
+        .iter()
+        .map(|path| {
+            let points: Vec<GlVec> = path.samples_optimal().map(GlVec::from).collect();
+            let mut warped: Vec<Point> = points.first().map(|p| warp(*p)).into_iter().collect();
+            for w in points.windows(2) {
+                let steps = ((w[1] - w[0]).length() / MAX_STEP).ceil().max(1.0) as usize;
+                warped.extend(
+                    (1..=steps).map(|i| warp(w[0].lerp(w[1], i as Float / steps as Float))),
+                );
+            }
+
+            let mut warped: Path = Polyline(warped).into();
+            warped.meta = path.meta.clone();
+            warped
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod warp_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{SweepArc, Vector};
+
+    #[test]
+    fn test_warp_between_lines() {
+        let inner: Path = Polyline(vec![point(0.0, 0.0), point(10.0, 0.0)]).into();
+        let outer: Path = Polyline(vec![point(0.0, 4.0), point(10.0, 2.0)]).into();
+        let diagonal: Path = Polyline(vec![point(0.0, 0.0), point(1.0, 1.0)]).into();
+
+        let warped = warp_between(&inner, &outer, &[diagonal.with_meta("layer", "fill")]);
+        assert_eq!(warped.len(), 1);
+        assert_eq!(warped[0].start(), point(0.0, 0.0));
+        assert_eq!(warped[0].end(), point(10.0, 2.0));
+        assert_eq!(warped[0].get_meta("layer"), Some("fill"));
+    }
+
+    #[test]
+    fn test_warp_between_circles() {
+        let center = point(0.0, 0.0);
+        let inner = Path::new(vec![Box::new(SweepArc::ellipse(
+            center,
+            Vector::from(point(5.0, 5.0)),
+        ))]);
+        let outer = Path::new(vec![Box::new(SweepArc::ellipse(
+            center,
+            Vector::from(point(10.0, 10.0)),
+        ))]);
+        let middle: Path = Polyline(vec![point(0.0, 0.5), point(1.0, 0.5)]).into();
+
+        let warped = warp_between(&inner, &outer, &[middle]);
+        for point in warped[0].sample_optimal() {
+            assert!((point.x.hypot(point.y) - 7.5).abs() < 1e-2);
+        }
+        assert!((warped[0].length() - Float::to_radians(360.0) * 7.5).abs() < 0.1);
+    }
+}
@@ -0,0 +1,332 @@
+use crate::{
+    affine_scale, affine_translation, flow_field::lattice, warp_between, Affine, Float,
+    MotifRenderer, Path, Point, Vector,
+};
+
+/// restarts after a contradiction before giving up
+const MAX_ATTEMPTS: u32 = 16;
+
+/// tile with an edge label on each side, right, top, left and bottom,
+/// neighbours fit where their touching labels are equal
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WfcTile {
+    pub edges: [u32; 4],
+    /// how often the tile is picked relative to the others
+    pub weight: Float,
+}
+
+impl WfcTile {
+    pub fn new(edges: [u32; 4]) -> Self {
+        Self { edges, weight: 1.0 }
+    }
+
+    pub fn with_weight(mut self, weight: Float) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+/// wave function collapse solver filling a grid with fitting tiles
+///
+/// the same seed always gives the same fill
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Wfc {
+    pub tiles: Vec<WfcTile>,
+    pub seed: u32,
+}
+
+impl Wfc {
+    pub fn new(tiles: Vec<WfcTile>) -> Self {
+        Self { tiles, seed: 0 }
+    }
+
+    pub fn with_seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// tile index of every cell row by row from the bottom,
+    /// `wrap` makes the last column the left neighbour of the first
+    ///
+    /// `None` when every attempt ran into a cell no tile fits
+    pub fn solve(&self, cols: usize, rows: usize, wrap: bool) -> Option<Vec<usize>> {
+        if self.tiles.is_empty() {
+            return None;
+        }
+        (0..MAX_ATTEMPTS)
+            .find_map(|attempt| self.attempt(cols, rows, wrap, self.seed.wrapping_add(attempt)))
+    }
+
+    /// solved grid of `cell` sized squares from `origin`,
+    /// `renderer` draws the tile of the index within the unit square
+    pub fn render_grid(
+        &self,
+        origin: Point,
+        cell: Float,
+        cols: usize,
+        rows: usize,
+        renderer: impl MotifRenderer,
+    ) -> Option<Vec<Path>> {
+        let cells = self.solve(cols, rows, false)?;
+        Some(place(&cells, cols, renderer, |col, row| {
+            affine_translation(offset(
+                origin.x + col as Float * cell,
+                origin.y + row as Float * cell,
+            )) * affine_scale(scale(cell, cell))
+        }))
+    }
+
+    /// solved grid bent into the band between two closed outlines,
+    /// columns run around along the outlines and rows from `inner` to `outer`
+    ///
+    /// the first and last columns fit each other so the band closes seamlessly
+    pub fn render_ring(
+        &self,
+        inner: &Path,
+        outer: &Path,
+        cols: usize,
+        rows: usize,
+        renderer: impl MotifRenderer,
+    ) -> Option<Vec<Path>> {
+        let cells = self.solve(cols, rows, true)?;
+        let (width, height) = (1.0 / cols as Float, 1.0 / rows as Float);
+        let drawing = place(&cells, cols, renderer, |col, row| {
+            affine_translation(offset(col as Float * width, row as Float * height))
+                * affine_scale(scale(width, height))
+        });
+        Some(warp_between(inner, outer, &drawing))
+    }
+
+    fn attempt(&self, cols: usize, rows: usize, wrap: bool, seed: u32) -> Option<Vec<usize>> {
+        let n = self.tiles.len();
+        let mut cells: Vec<Vec<bool>> = vec![vec![true; n]; cols * rows];
+        let mut draws = 0;
+        let mut random = || {
+            draws += 1;
+            lattice(draws, 0, seed)
+        };
+
+        // tiles that fit nothing are ruled out before the first pick
+        for cell in 0..cells.len() {
+            if !self.propagate(&mut cells, cell, cols, rows, wrap) {
+                return None;
+            }
+        }
+
+        loop {
+            // the undecided cell with the fewest options, ties broken at random
+            let next = cells
+                .iter()
+                .enumerate()
+                .map(|(i, options)| (i, options.iter().filter(|o| **o).count()))
+                .filter(|(_, count)| *count > 1)
+                .map(|(i, count)| (i, count as Float + random() * 0.5))
+                .min_by(|a, b| a.1.total_cmp(&b.1));
+            let Some((cell, _)) = next else {
+                break;
+            };
+
+            let total: Float = (0..n)
+                .filter(|t| cells[cell][*t])
+                .map(|t| self.tiles[t].weight.max(0.0))
+                .sum();
+            let mut pick = random() * total;
+            let chosen = (0..n)
+                .filter(|t| cells[cell][*t])
+                .find(|t| {
+                    pick -= self.tiles[*t].weight.max(0.0);
+                    pick <= 0.0
+                })
+                .or_else(|| (0..n).rfind(|t| cells[cell][*t]))?;
+            cells[cell] = (0..n).map(|t| t == chosen).collect();
+
+            if !self.propagate(&mut cells, cell, cols, rows, wrap) {
+                return None;
+            }
+        }
+
+        cells
+            .iter()
+            .map(|options| options.iter().position(|o| *o))
+            .collect()
+    }
+
+    /// narrows the neighbours down to tiles fitting the remaining options,
+    /// `false` when a cell runs out of them
+    fn propagate(
+        &self,
+        cells: &mut [Vec<bool>],
+        from: usize,
+        cols: usize,
+        rows: usize,
+        wrap: bool,
+    ) -> bool {
+        let mut stack = vec![from];
+        while let Some(cell) = stack.pop() {
+            let (col, row) = (cell % cols, cell / cols);
+            for side in 0..4 {
+                let neighbour = match side {
+                    0 if col + 1 < cols => cell + 1,
+                    0 if wrap => cell + 1 - cols,
+                    1 if row + 1 < rows => cell + cols,
+                    2 if col > 0 => cell - 1,
+                    2 if wrap => cell + cols - 1,
+                    3 if row > 0 => cell - cols,
+                    _ => continue,
+                };
+                let facing = (side + 2) % 4;
+
+                let mut changed = false;
+                for t in 0..self.tiles.len() {
+                    if !cells[neighbour][t] {
+                        continue;
+                    }
+                    let edge = self.tiles[t].edges[facing];
+                    let fits = (0..self.tiles.len())
+                        .any(|o| cells[cell][o] && self.tiles[o].edges[side] == edge);
+                    if !fits {
+                        cells[neighbour][t] = false;
+                        changed = true;
+                    }
+                }
+
+                if changed {
+                    if !cells[neighbour].iter().any(|o| *o) {
+                        return false;
+                    }
+                    stack.push(neighbour);
+                }
+            }
+        }
+        true
+    }
+}
+
+/// renders every cell's tile and moves it into place
+fn place(
+    cells: &[usize],
+    cols: usize,
+    mut renderer: impl MotifRenderer,
+    placement: impl Fn(usize, usize) -> Affine,
+) -> Vec<Path> {
+    cells
+        .iter()
+        .enumerate()
+        .flat_map(|(i, tile)| {
+            let affine = placement(i % cols, i / cols);
+            renderer
+                .render(*tile)
+                .into_iter()
+                .map(move |path| path.transformed(affine))
+        })
+        .collect()
+}
+
+fn offset(x: Float, y: Float) -> Vector {
+    Vector {
+        x,
+        y,
+        #[cfg(feature = "3d")]
+        z: 0.0,
+    }
+}
+
+fn scale(x: Float, y: Float) -> Vector {
+    Vector {
+        x,
+        y,
+        #[cfg(feature = "3d")]
+        z: 1.0,
+    }
+}
+
+#[cfg(test)]
+mod wfc_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{LineSegment, VectorValuedFn};
+
+    /// pipes, edge `1` has a pipe crossing it and `0` is blank
+    fn pipes() -> Wfc {
+        Wfc::new(vec![
+            WfcTile::new([0, 0, 0, 0]),
+            WfcTile::new([1, 0, 1, 0]),
+            WfcTile::new([0, 1, 0, 1]),
+            WfcTile::new([1, 1, 0, 0]),
+            WfcTile::new([0, 1, 1, 0]),
+            WfcTile::new([0, 0, 1, 1]),
+            WfcTile::new([1, 0, 0, 1]),
+        ])
+    }
+
+    fn fits(wfc: &Wfc, cells: &[usize], cols: usize, rows: usize, wrap: bool) -> bool {
+        (0..cols * rows).all(|i| {
+            let (col, row) = (i % cols, i / cols);
+            let tile = wfc.tiles[cells[i]].edges;
+            let right = (col + 1 < cols || wrap)
+                .then(|| wfc.tiles[cells[row * cols + (col + 1) % cols]].edges[2]);
+            let above = (row + 1 < rows).then(|| wfc.tiles[cells[i + cols]].edges[3]);
+            right.is_none_or(|edge| edge == tile[0]) && above.is_none_or(|edge| edge == tile[1])
+        })
+    }
+
+    #[test]
+    fn test_solve_fits_neighbours() {
+        for seed in 0..8 {
+            let wfc = pipes().with_seed(seed);
+            let cells = wfc.solve(8, 6, false).unwrap();
+            assert_eq!(cells.len(), 48);
+            assert!(fits(&wfc, &cells, 8, 6, false));
+            assert_eq!(Some(cells), wfc.solve(8, 6, false));
+
+            let ring = wfc.solve(8, 6, true).unwrap();
+            assert!(fits(&wfc, &ring, 8, 6, true));
+        }
+    }
+
+    #[test]
+    fn test_weights_and_contradictions() {
+        let blank = pipes().tiles[0].with_weight(0.0);
+        let mut wfc = pipes();
+        wfc.tiles[0] = blank;
+        let cells = wfc.solve(6, 6, true).unwrap();
+        assert!(cells.iter().filter(|t| **t == 0).count() < 6);
+
+        // nothing fits next to itself
+        let stubborn = Wfc::new(vec![WfcTile::new([1, 1, 2, 2])]);
+        assert_eq!(stubborn.solve(2, 2, false), None);
+        assert_eq!(Wfc::new(vec![]).solve(2, 2, false), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let tick = |_: usize| {
+            vec![Path::new(vec![Box::new(LineSegment {
+                start: point(0.5, 0.0),
+                end: point(0.5, 1.0),
+            })])]
+        };
+
+        let grid = pipes()
+            .render_grid(point(10.0, 10.0), 2.0, 3, 2, tick)
+            .unwrap();
+        assert_eq!(grid.len(), 6);
+        for path in &grid {
+            assert!((path.length() - 2.0).abs() < 1e-4);
+            let start = path.start();
+            assert!(start.x > 10.0 && start.x < 16.0 && start.y >= 10.0 && start.y < 14.0);
+        }
+
+        let (inner, outer) = (
+            Path::circle(point(0.0, 0.0), 3.0),
+            Path::circle(point(0.0, 0.0), 5.0),
+        );
+        let ring = pipes().render_ring(&inner, &outer, 8, 1, tick).unwrap();
+        assert_eq!(ring.len(), 8);
+        for path in &ring {
+            assert!((path.length() - 2.0).abs() < 1e-2);
+        }
+    }
+}
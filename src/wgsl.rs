@@ -0,0 +1,197 @@
+use std::fmt::Write;
+
+use crate::{Float, Path, Point, Primitive, VectorValuedFn};
+
+/// points sampled from segments without exact geometry
+const FALLBACK_SAMPLES: usize = 32;
+
+#[cfg(feature = "3d")]
+const VEC: &str = "vec3<f32>";
+#[cfg(feature = "2d")]
+const VEC: &str = "vec2<f32>";
+
+impl Path {
+    /// WGSL function `name(t: f32)` evaluating the whole path,
+    /// `t` runs over the segments in proportion to their length like [`Path::eval`]
+    ///
+    /// lines, bezier curves and arcs are written exactly,
+    /// other segments as a polyline of evenly spaced samples
+    pub fn to_shader_code(&self, name: &str) -> String {
+        let mut out = String::new();
+        writeln!(out, "fn {name}(t: f32) -> {VEC} {{").unwrap();
+        writeln!(out, "    let s = clamp(t, 0.0, 1.0);").unwrap();
+
+        let segments = self.segments();
+        let total = self.length();
+        if segments.is_empty() || total <= 0.0 {
+            let start = segments.first().map(|s| s.start()).unwrap_or(Point {
+                x: 0.0,
+                y: 0.0,
+                #[cfg(feature = "3d")]
+                z: 0.0,
+            });
+            writeln!(out, "    return {};", vec(start)).unwrap();
+            out.push_str("}\n");
+            return out;
+        }
+
+        let mut from = 0.0;
+        for (i, segment) in segments.iter().enumerate() {
+            let last = i + 1 == segments.len();
+            let to = if last {
+                1.0
+            } else {
+                from + segment.length() / total
+            };
+            if to <= from && !last {
+                continue;
+            }
+
+            let indent = if last {
+                "    "
+            } else {
+                writeln!(out, "    if (s < {to:?}) {{").unwrap();
+                "        "
+            };
+            writeln!(
+                out,
+                "{indent}let u = (s - {from:?}) / {:?};",
+                (to - from).max(Float::EPSILON)
+            )
+            .unwrap();
+            write_segment(&mut out, indent, segment.as_ref());
+            if !last {
+                out.push_str("    }\n");
+            }
+            from = to;
+        }
+
+        out.push_str("}\n");
+        out
+    }
+}
+
+fn write_segment(out: &mut String, indent: &str, segment: &dyn VectorValuedFn) {
+    match segment.primitive() {
+        Some(Primitive::Line(l)) => {
+            writeln!(
+                out,
+                "{indent}return mix({}, {}, u);",
+                vec(l.start),
+                vec(l.end)
+            )
+            .unwrap();
+        }
+        Some(Primitive::QuadraticCurve(c)) => {
+            writeln!(
+                out,
+                "{indent}let v = 1.0 - u;\n{indent}return v * v * {} + 2.0 * v * u * {} + u * u * {};",
+                vec(c.start),
+                vec(c.control),
+                vec(c.end)
+            )
+            .unwrap();
+        }
+        Some(Primitive::CubicCurve(c)) => {
+            writeln!(
+                out,
+                "{indent}let v = 1.0 - u;\n{indent}return v * v * v * {} + 3.0 * v * v * u * {} + 3.0 * v * u * u * {} + u * u * u * {};",
+                vec(c.start),
+                vec(c.control1),
+                vec(c.control2),
+                vec(c.end)
+            )
+            .unwrap();
+        }
+        Some(Primitive::Arc(arc)) => {
+            let r = arc.radius;
+            #[cfg(feature = "3d")]
+            let trig = format!(
+                "{VEC}(cos(a), sin(a), sin(a)) * {VEC}({:?}, {:?}, {:?})",
+                r.x, r.y, r.z
+            );
+            #[cfg(feature = "2d")]
+            let trig = format!("{VEC}(cos(a), sin(a)) * {VEC}({:?}, {:?})", r.x, r.y);
+            writeln!(
+                out,
+                "{indent}let a = {:?} + {:?} * u;\n{indent}return {} + {trig};",
+                arc.start_angle.to_radians(),
+                arc.sweep_angle.to_radians(),
+                vec(arc.center)
+            )
+            .unwrap();
+        }
+        None => {
+            let points = segment.sample_evenly(FALLBACK_SAMPLES);
+            let items: Vec<String> = points.iter().map(|p| vec((*p).into())).collect();
+            writeln!(
+                out,
+                "{indent}var points = array<{VEC}, {}>({});",
+                points.len(),
+                items.join(", ")
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{indent}let f = u * {:?};\n{indent}let k = min(u32(f), {}u);\n{indent}return mix(points[k], points[k + 1u], f - f32(k));",
+                (points.len() - 1) as Float,
+                points.len() - 2
+            )
+            .unwrap();
+        }
+    }
+}
+
+fn vec(p: Point) -> String {
+    #[cfg(feature = "3d")]
+    return format!("{VEC}({:?}, {:?}, {:?})", p.x, p.y, p.z);
+    #[cfg(feature = "2d")]
+    return format!("{VEC}({:?}, {:?})", p.x, p.y);
+}
+
+#[cfg(test)]
+mod wgsl_tests {
+    use super::*;
+    use crate::test_util::point;
+    use crate::{CubicCurve, SweepArc, Vector};
+
+    #[test]
+    fn test_rectangle_shader_code() {
+        let square = Path::rectangle(point(0.0, 0.0), Vector::from(point(1.0, 1.0)));
+        let code = square.to_shader_code("square");
+
+        assert!(code.starts_with(&format!("fn square(t: f32) -> {VEC} {{")));
+        assert_eq!(code.matches("return mix(").count(), 4);
+        assert_eq!(code.matches("if (s < ").count(), 3);
+        assert!(code.contains("if (s < 0.25)") && code.contains("if (s < 0.75)"));
+        assert_eq!(code.matches('{').count(), code.matches('}').count());
+    }
+
+    #[test]
+    fn test_curves_and_fallback() {
+        let center = point(0.0, 0.0);
+        let mut path = Path::new(vec![Box::new(SweepArc::ellipse(
+            center,
+            Vector::from(point(2.0, 2.0)),
+        ))]);
+        path.push(Box::new(CubicCurve {
+            start: point(2.0, 0.0),
+            control1: point(3.0, 1.0),
+            control2: point(4.0, -1.0),
+            end: point(5.0, 0.0),
+        }));
+        // reversed arcs have no exact primitive
+        path.push(Box::new(crate::Reversed {
+            source: SweepArc::ellipse(point(4.0, 0.0), Vector::from(point(1.0, 1.0))),
+        }));
+        let code = path.to_shader_code("shape");
+
+        assert!(code.contains("cos(a)"));
+        assert!(code.contains("3.0 * v * v * u"));
+        assert!(code.contains(&format!("array<{VEC}, {FALLBACK_SAMPLES}>")));
+        assert_eq!(code.matches("return ").count(), 3);
+
+        let empty = Path::default().to_shader_code("empty");
+        assert_eq!(empty.matches("return ").count(), 1);
+    }
+}